@@ -45,7 +45,7 @@ fn chinese_example(chinese: &Dictionary<Chinese>) {
 
     assert_eq!(definition.written, vec!["熱", "热"]);
     assert_eq!(definition.count, 2);
-    assert_eq!(definition.pronunciations.get(0).unwrap(), "re4");
+    assert_eq!(definition.pronunciations.first().unwrap(), "re4");
 }
 
 fn lao_example(lao: &Dictionary<Laotian>) {
@@ -57,8 +57,8 @@ fn lao_example(lao: &Dictionary<Laotian>) {
     dbg!(eat);
 
     assert_eq!(eat.written, vec!["ກິນ"]);
-    assert_eq!(eat.pronunciations.get(0).unwrap(), "kin");
-    assert_eq!(eat.translations.get(0).unwrap(), "eat");
+    assert_eq!(eat.pronunciations.first().unwrap(), "kin");
+    assert_eq!(eat.translations.first().unwrap(), "eat");
 }
 
 fn chinese_example_to_csv(chinese: &Dictionary<Chinese>) {
@@ -0,0 +1,347 @@
+#[cfg(feature = "chinese")]
+use crate::chinese::pinyin;
+use crate::dictionary::Language;
+use crate::error::DictionaryError;
+use crate::export::write_error;
+use crate::html::escape_html;
+use crate::word::{Word, WordParserResult};
+use std::io::Write;
+
+/// Default template of the front of the cards
+const DEFAULT_FRONT: &str = "{word}";
+
+/// Default template of the back of the cards
+const DEFAULT_BACK: &str = "{pronunciation}<br>{translation}<br><i>{example}</i>";
+
+/// Default note type of Anki which has a front and a back field
+const DEFAULT_NOTETYPE: &str = "Basic";
+
+/// Characters which end a sentence. They're used to find the example of a word in the source text
+const SENTENCE_END: [char; 9] = ['。', '！', '？', '；', '.', '!', '?', ';', '\n'];
+
+/// Offset basis of the 64 bits FNV-1a hash
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+
+/// Prime of the 64 bits FNV-1a hash
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Export the words of a parse result to a tab separated file which can be imported in Anki.
+/// Each note has a guid computed from the language and the word so that importing the same
+/// words again updates the existing notes instead of creating duplicates.
+///
+/// The templates of the front and the back of the cards can use the following placeholders:
+/// {word}, {written}, {pronunciation}, {translation} and {example}
+#[derive(Debug, Clone)]
+pub struct AnkiExporter {
+    language: Language,
+    front: String,
+    back: String,
+    notetype: String,
+    deck: Option<String>,
+}
+
+impl AnkiExporter {
+    /// Create an exporter with the default templates
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - Language
+    pub fn new(language: Language) -> Self {
+        AnkiExporter {
+            language,
+            front: DEFAULT_FRONT.to_string(),
+            back: DEFAULT_BACK.to_string(),
+            notetype: DEFAULT_NOTETYPE.to_string(),
+            deck: None,
+        }
+    }
+
+    /// Set the template of the front of the cards
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - S
+    pub fn with_front<S: AsRef<str>>(mut self, template: S) -> Self {
+        self.front = template.as_ref().to_string();
+        self
+    }
+
+    /// Set the template of the back of the cards
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - S
+    pub fn with_back<S: AsRef<str>>(mut self, template: S) -> Self {
+        self.back = template.as_ref().to_string();
+        self
+    }
+
+    /// Set the note type used by Anki to import the notes
+    ///
+    /// # Arguments
+    ///
+    /// * `notetype` - S
+    pub fn with_notetype<S: AsRef<str>>(mut self, notetype: S) -> Self {
+        self.notetype = notetype.as_ref().to_string();
+        self
+    }
+
+    /// Set the deck in which the notes are imported
+    ///
+    /// # Arguments
+    ///
+    /// * `deck` - S
+    pub fn with_deck<S: AsRef<str>>(mut self, deck: S) -> Self {
+        self.deck = Some(deck.as_ref().to_string());
+        self
+    }
+
+    /// Export the words to a tab separated file. The source text is used to find an example sentence
+    /// for each word
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - &WordParserResult
+    /// * `source` - &str
+    pub fn to_tsv(
+        &self,
+        words: &WordParserResult,
+        source: &str,
+    ) -> Result<String, DictionaryError> {
+        let mut buffer = Vec::new();
+        self.write_to(words, source, &mut buffer)?;
+
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    /// Write the words to a writer as a tab separated file. Each note is written as soon as it's rendered
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - &WordParserResult
+    /// * `source` - &str
+    /// * `writer` - &mut W
+    pub fn write_to<W: Write>(
+        &self,
+        words: &WordParserResult,
+        source: &str,
+        writer: &mut W,
+    ) -> Result<(), DictionaryError> {
+        let mut header = String::from("#separator:tab\n#html:true\n");
+        header.push_str(&format!("#notetype:{}\n", self.notetype));
+        if let Some(deck) = &self.deck {
+            header.push_str(&format!("#deck:{deck}\n"));
+        }
+        header.push_str("#guid column:1\n#tags column:4\n");
+        writer.write_all(header.as_bytes()).map_err(write_error)?;
+
+        for (key, word) in words {
+            let fields = [
+                guid(self.language, key),
+                clean_field(&self.render(&self.front, key, word, source)),
+                clean_field(&self.render(&self.back, key, word, source)),
+                self.tags(word).join(" "),
+            ];
+
+            writeln!(writer, "{}", fields.join("\t")).map_err(write_error)?;
+        }
+
+        Ok(())
+    }
+
+    /// Replace the placeholders of a template by the values of a word
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - &str
+    /// * `key` - &str
+    /// * `word` - &Word
+    /// * `source` - &str
+    fn render(&self, template: &str, key: &str, word: &Word, source: &str) -> String {
+        template
+            .replace("{word}", &escape_html(key))
+            .replace("{written}", &escape_html(&word.written.join(", ")))
+            .replace("{pronunciation}", &escape_html(&self.pronunciation(word)))
+            .replace("{translation}", &escape_html(&word.translations.join("; ")))
+            .replace(
+                "{example}",
+                &escape_html(find_example(source, key, word).unwrap_or_default()),
+            )
+    }
+
+    /// Get the pronunciation of a word. The pinyin of a chinese word is written with tone marks
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &Word
+    fn pronunciation(&self, word: &Word) -> String {
+        match self.language {
+            // the cedict stores one syllable per pronunciation
+            #[cfg(feature = "chinese")]
+            Language::Chinese => pinyin::to_tone_marks(&word.pronunciations.join(" ")),
+            _ => word.pronunciations.join(", "),
+        }
+    }
+
+    /// Get the tags of a note: the language and the levels of the word e.g: chinese hsk2-3
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &Word
+    fn tags(&self, word: &Word) -> Vec<String> {
+        std::iter::once(language_name(self.language).to_string())
+            .chain(word.levels.iter().map(|level| level.to_string()))
+            .collect()
+    }
+}
+
+/// Compute a stable guid for a note with the FNV-1a hash of the language and the word
+///
+/// # Arguments
+///
+/// * `language` - Language
+/// * `key` - &str
+pub fn guid(language: Language, key: &str) -> String {
+    let hash = format!("{}:{key}", language_name(language))
+        .bytes()
+        .fold(FNV_OFFSET, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+        });
+
+    format!("{hash:016x}")
+}
+
+/// Get the name of a language used in the tags & the guid of the notes
+///
+/// # Arguments
+///
+/// * `language` - Language
+fn language_name(language: Language) -> &'static str {
+    match language {
+        Language::Chinese => "chinese",
+        Language::Laotian => "laotian",
+    }
+}
+
+/// Find the first sentence of the source text which contains the word
+///
+/// # Arguments
+///
+/// * `source` - &'a str
+/// * `key` - &str
+/// * `word` - &Word
+fn find_example<'a>(source: &'a str, key: &str, word: &Word) -> Option<&'a str> {
+    source
+        .split_inclusive(SENTENCE_END)
+        .map(str::trim)
+        .find(|sentence| {
+            sentence.contains(key)
+                || word
+                    .written
+                    .iter()
+                    .any(|written| !written.is_empty() && sentence.contains(written.as_str()))
+        })
+}
+
+/// Remove the characters which would break the tab separated file
+///
+/// # Arguments
+///
+/// * `value` - &str
+fn clean_field(value: &str) -> String {
+    value.replace('\t', " ").replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word::{Level, LevelSystem};
+
+    fn words() -> WordParserResult {
+        WordParserResult::from([(
+            "喜歡".to_string(),
+            Word {
+                written: vec!["喜歡".to_string(), "喜欢".to_string()],
+                pronunciations: vec!["xi3".to_string(), "huan5".to_string()],
+                translations: vec!["to like".to_string(), "to be fond of".to_string()],
+                levels: vec![Level {
+                    system: LevelSystem::Hsk2,
+                    level: 1,
+                }],
+                ..Default::default()
+            },
+        )])
+    }
+
+    #[test]
+    fn expect_to_compute_stable_guid() {
+        assert_eq!(
+            guid(Language::Chinese, "喜歡"),
+            guid(Language::Chinese, "喜歡")
+        );
+        assert_ne!(
+            guid(Language::Chinese, "喜歡"),
+            guid(Language::Laotian, "喜歡")
+        );
+        assert_eq!(guid(Language::Chinese, "").len(), 16);
+    }
+
+    #[test]
+    fn expect_to_export_tsv() {
+        let exporter = AnkiExporter::new(Language::Chinese)
+            .with_back("{pronunciation} - {translation} - {example}")
+            .with_deck("Chinese::Vocabulary");
+
+        let tsv = exporter
+            .to_tsv(&words(), "你好。你喜歡開車嗎? 我喜歡")
+            .unwrap();
+        let lines = tsv.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[0], "#separator:tab");
+        assert!(lines.contains(&"#deck:Chinese::Vocabulary"));
+
+        let fields = lines.last().unwrap().split('\t').collect::<Vec<_>>();
+        assert_eq!(fields[0], guid(Language::Chinese, "喜歡"));
+        assert_eq!(fields[1], "喜歡");
+        #[cfg(feature = "chinese")]
+        assert_eq!(
+            fields[2],
+            "xǐ huan - to like; to be fond of - 你喜歡開車嗎?"
+        );
+        assert_eq!(fields[3], "chinese hsk2-1");
+    }
+
+    #[test]
+    fn expect_to_leave_example_empty_without_sentence() {
+        let exporter = AnkiExporter::new(Language::Chinese).with_back("{translation}|{example}");
+
+        let tsv = exporter.to_tsv(&words(), "你好。").unwrap();
+        let fields = tsv.lines().last().unwrap().split('\t').collect::<Vec<_>>();
+
+        assert_eq!(fields[2], "to like; to be fond of|");
+    }
+
+    #[test]
+    fn expect_to_export_laotian_tsv() {
+        let words = WordParserResult::from([(
+            "ຮັກ".to_string(),
+            Word {
+                written: vec!["ຮັກ".to_string()],
+                pronunciations: vec!["hak".to_string()],
+                translations: vec!["\"to love\" <someone>".to_string()],
+                ..Default::default()
+            },
+        )]);
+        let exporter = AnkiExporter::new(Language::Laotian)
+            .with_front("{translation}")
+            .with_back("{pronunciation} - {example}");
+
+        let tsv = exporter.to_tsv(&words, "ຂ້ອຍຮັກເຈົ້າ. ສະບາຍດີ").unwrap();
+        let fields = tsv.lines().last().unwrap().split('\t').collect::<Vec<_>>();
+
+        assert_eq!(fields[0], guid(Language::Laotian, "ຮັກ"));
+        assert_eq!(fields[1], "&quot;to love&quot; &lt;someone&gt;");
+        assert_eq!(fields[2], "hak - ຂ້ອຍຮັກເຈົ້າ.");
+        assert_eq!(fields[3], "laotian");
+    }
+}
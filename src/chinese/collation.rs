@@ -0,0 +1,171 @@
+use super::pinyin;
+use super::unihan::{self, RadicalStroke};
+use crate::collation::Collator;
+use crate::error::DictionaryError;
+use crate::util;
+use crate::word::Word;
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::path::PathBuf;
+
+/// Field of the Unihan database containing the radical and the residual strokes of a character
+const RADICAL_STROKE_FIELD: &str = "kRSUnicode";
+
+/// Tone used by the cedict for the neutral tone
+const NEUTRAL_TONE: u8 = 5;
+
+/// Key of a character. Characters are compared by their radical, then by their residual strokes and
+/// then by their code point
+type CharacterKey = (u32, bool, i32, u32);
+
+/// Collator sorting chinese words by their pinyin. Syllables are compared alphabetically without their
+/// tones first and the tones are only used when two words have the same syllables e.g: mā, má, mǎ, mà, ma
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PinyinCollator;
+
+impl Collator for PinyinCollator {
+    type Key = (Vec<String>, Vec<u8>);
+
+    fn sort_key(&self, key: &str, word: &Word) -> Self::Key {
+        if word.pronunciations.is_empty() {
+            return (vec![key.to_lowercase()], Vec::new());
+        }
+
+        // the cedict stores one pinyin syllable per pronunciation e.g: 你好 -> ["ni3", "hao3"]
+        word.pronunciations
+            .iter()
+            .flat_map(|pronunciation| pronunciation.split_whitespace())
+            .map(|syllable| match pinyin::parse_syllable(syllable) {
+                Some(s) => (s.base, s.tone.unwrap_or(NEUTRAL_TONE)),
+                // punctuations and latin letters are kept as is
+                None => (syllable.to_lowercase(), 0),
+            })
+            .unzip()
+    }
+}
+
+/// Collator sorting chinese words by the radical of their characters and then by the number of strokes
+/// as it's done in a paper dictionary. The radicals and the strokes are loaded from the Unihan database
+/// e.g: Unihan_IRGSources.txt or Unihan_RadicalStrokeCounts.txt for older versions
+#[derive(Debug, Clone, Default)]
+pub struct RadicalStrokeCollator {
+    characters: HashMap<char, RadicalStroke>,
+}
+
+impl RadicalStrokeCollator {
+    /// Load the radicals and the strokes from a Unihan data file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - PathBuf
+    pub fn load(path: PathBuf) -> Result<Self, DictionaryError> {
+        Self::from_reader(util::open_reader(path, DictionaryError::ChineseDictionary)?)
+    }
+
+    /// Load the radicals and the strokes from a reader with the format of the Unihan database
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - R
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self, DictionaryError> {
+        let mut characters = HashMap::new();
+        unihan::read_entries(reader, |character, field, value| {
+            if field == RADICAL_STROKE_FIELD
+                && let Some(radical_stroke) = RadicalStroke::parse(value)
+            {
+                characters.insert(character, radical_stroke);
+            }
+        })?;
+
+        Ok(RadicalStrokeCollator { characters })
+    }
+
+    /// Get the radical and the residual strokes of a character
+    ///
+    /// # Arguments
+    ///
+    /// * `character` - char
+    pub fn radical_stroke(&self, character: char) -> Option<&RadicalStroke> {
+        self.characters.get(&character)
+    }
+}
+
+impl Collator for RadicalStrokeCollator {
+    type Key = Vec<CharacterKey>;
+
+    fn sort_key(&self, key: &str, _: &Word) -> Self::Key {
+        key.chars()
+            .map(|c| match self.characters.get(&c) {
+                Some(rs) => (rs.radical, rs.simplified, rs.residual_strokes, c as u32),
+                // characters without radical are sorted after the chinese characters
+                None => (u32::MAX, false, 0, c as u32),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collation::SortedWords;
+    use crate::word::WordParserResult;
+
+    const UNIHAN: &str = "# Unihan_IRGSources.txt
+U+4E00\tkRSUnicode\t1.0
+U+4E09\tkRSUnicode\t1.2
+U+4EBA\tkRSUnicode\t9.0
+U+4F60\tkRSUnicode\t9.5
+U+4F60\tkTotalStrokes\t7
+U+597D\tkRSUnicode\t38.3
+";
+
+    fn word(pronunciation: &str) -> Word {
+        Word {
+            pronunciations: pronunciation.split(' ').map(String::from).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn expect_to_sort_by_pinyin_with_tone_as_tiebreaker() {
+        let words = WordParserResult::from([
+            ("買".to_string(), word("mai3")),
+            ("媽".to_string(), word("ma1")),
+            ("馬".to_string(), word("ma3")),
+            ("嗎".to_string(), word("ma5")),
+            ("麻".to_string(), word("ma2")),
+            ("馬上".to_string(), word("ma3 shang4")),
+        ]);
+
+        let sorted = words
+            .sorted_by(&PinyinCollator)
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+
+        assert_eq!(sorted, vec!["媽", "麻", "馬", "嗎", "馬上", "買"]);
+    }
+
+    #[test]
+    fn expect_to_sort_by_radical_and_strokes() {
+        let collator = RadicalStrokeCollator::from_reader(UNIHAN.as_bytes()).unwrap();
+        assert_eq!(collator.radical_stroke('你').unwrap().residual_strokes, 5);
+
+        let words = WordParserResult::from([
+            ("好".to_string(), Word::default()),
+            ("你".to_string(), Word::default()),
+            ("人".to_string(), Word::default()),
+            ("三".to_string(), Word::default()),
+            ("A".to_string(), Word::default()),
+            ("一".to_string(), Word::default()),
+        ]);
+
+        let sorted = words
+            .sorted_by(&collator)
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+
+        assert_eq!(sorted, vec!["一", "三", "人", "你", "好", "A"]);
+    }
+}
@@ -0,0 +1,432 @@
+use super::unihan::CODE_POINT_PREFIX;
+use crate::dictionary::{Chinese, Dictionary};
+use crate::error::DictionaryError;
+use crate::util;
+use crate::word::Word;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::io::BufRead;
+use std::path::PathBuf;
+
+/// Maximum depth of a decomposition. It avoids looping forever on a file which contains a cycle
+const MAX_DEPTH: usize = 16;
+
+/// A component of a character as described by an Ideographic Description Sequence
+/// e.g: 想 -> ⿱相心 -> ⿱(⿰木目)心
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Component {
+    /// A character which can't be decomposed further
+    Character(char),
+    /// A component which isn't encoded in unicode e.g: &CDP-8BBF; or {1}
+    Unencoded(String),
+    /// Parts arranged with an ideographic description character e.g: ⿱
+    Composition {
+        /// Character represented by the composition. None for a part which isn't a character
+        character: Option<char>,
+        operator: char,
+        parts: Vec<Component>,
+    },
+}
+
+impl Component {
+    /// Get the character represented by the component if any
+    pub fn character(&self) -> Option<char> {
+        match self {
+            Component::Character(c) => Some(*c),
+            Component::Unencoded(_) => None,
+            Component::Composition { character, .. } => *character,
+        }
+    }
+
+    /// Get the characters which compose the component at the first level. Parts which aren't characters
+    /// are replaced by their own parts e.g: 想 -> 相, 心
+    pub fn parts(&self) -> Vec<char> {
+        let Component::Composition { parts, .. } = self else {
+            return Vec::new();
+        };
+
+        parts
+            .iter()
+            .flat_map(|part| match part.character() {
+                Some(c) => vec![c],
+                None => part.parts(),
+            })
+            .collect()
+    }
+
+    /// Check whether a character is used at any level of the component
+    ///
+    /// # Arguments
+    ///
+    /// * `character` - char
+    pub fn contains(&self, character: char) -> bool {
+        match self {
+            Component::Composition { parts, .. } => parts
+                .iter()
+                .any(|part| part.character() == Some(character) || part.contains(character)),
+            _ => false,
+        }
+    }
+}
+
+/// Meaning of a component of a character taken from the cedict
+#[derive(Debug, Clone)]
+pub struct ComponentMeaning<'a> {
+    pub component: char,
+    /// Definition of the component. None when the component is not a word of the cedict
+    pub word: Option<&'a Word>,
+}
+
+/// Components of a character of a word with their meanings e.g: 想 = 相 + 心
+#[derive(Debug, Clone)]
+pub struct CharacterBreakdown<'a> {
+    pub character: char,
+    pub components: Vec<ComponentMeaning<'a>>,
+}
+
+/// Ideographic Description Sequences of the characters e.g: the ids.txt file of cjkvi-ids
+#[derive(Debug, Default)]
+pub struct Ids {
+    sequences: HashMap<char, String>,
+    /// Characters which directly use a component
+    parents: HashMap<char, Vec<char>>,
+}
+
+impl Ids {
+    /// Load the sequences from a reader. Each line contains a code point, the character and one or more
+    /// sequences e.g: "U+60F3<TAB>想<TAB>⿱相心". Only the first sequence of a character is used
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - R
+    fn read<R: BufRead>(&mut self, reader: R) -> Result<(), DictionaryError> {
+        for line in reader.lines() {
+            let line = line.map_err(|err| DictionaryError::ChineseDictionary(err.to_string()))?;
+            let mut columns = line.split('\t');
+            let (Some(code_point), Some(character), Some(sequence)) =
+                (columns.next(), columns.next(), columns.next())
+            else {
+                continue;
+            };
+
+            if !code_point.starts_with(CODE_POINT_PREFIX) {
+                continue;
+            }
+
+            let mut chars = character.chars();
+            let (Some(character), None) = (chars.next(), chars.next()) else {
+                continue;
+            };
+
+            let sequence = clean_sequence(sequence);
+            if sequence.is_empty() {
+                continue;
+            }
+
+            if let Some(component) = parse_sequence(&sequence) {
+                for part in component.parts() {
+                    let parents = self.parents.entry(part).or_default();
+                    // the same file may be loaded more than once
+                    if part != character && !parents.contains(&character) {
+                        parents.push(character);
+                    }
+                }
+            }
+
+            self.sequences.insert(character, sequence);
+        }
+
+        Ok(())
+    }
+
+    /// Decompose a character into a tree of components
+    ///
+    /// # Arguments
+    ///
+    /// * `character` - char
+    /// * `depth` - usize
+    fn decompose(&self, character: char, depth: usize) -> Component {
+        let Some(sequence) = self.sequences.get(&character) else {
+            return Component::Character(character);
+        };
+
+        match parse_sequence(sequence) {
+            Some(Component::Composition {
+                operator, parts, ..
+            }) if depth < MAX_DEPTH => Component::Composition {
+                character: Some(character),
+                operator,
+                parts: parts
+                    .into_iter()
+                    .map(|part| self.expand(part, depth + 1))
+                    .collect(),
+            },
+            _ => Component::Character(character),
+        }
+    }
+
+    /// Replace the characters of a component by their own decomposition
+    ///
+    /// # Arguments
+    ///
+    /// * `component` - Component
+    /// * `depth` - usize
+    fn expand(&self, component: Component, depth: usize) -> Component {
+        match component {
+            Component::Character(c) => self.decompose(c, depth),
+            Component::Composition {
+                character,
+                operator,
+                parts,
+            } => Component::Composition {
+                character,
+                operator,
+                parts: parts
+                    .into_iter()
+                    .map(|part| self.expand(part, depth))
+                    .collect(),
+            },
+            unencoded => unencoded,
+        }
+    }
+}
+
+impl Dictionary<Chinese> {
+    /// Load an Ideographic Description Sequence file such as the ids.txt of cjkvi-ids
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - PathBuf
+    pub fn load_ids(&mut self, path: PathBuf) -> Result<(), DictionaryError> {
+        self.load_ids_from_reader(util::open_reader(path, DictionaryError::ChineseDictionary)?)
+    }
+
+    /// Load Ideographic Description Sequences from a reader with the format of cjkvi-ids
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - R
+    pub fn load_ids_from_reader<R: BufRead>(&mut self, reader: R) -> Result<(), DictionaryError> {
+        self.data.ids.read(reader)
+    }
+
+    /// Decompose a character into a tree of components e.g: 想 -> ⿱(⿰木目)心. None is returned
+    /// when the character has no known decomposition
+    ///
+    /// # Arguments
+    ///
+    /// * `character` - char
+    pub fn decompose(&self, character: char) -> Option<Component> {
+        match self.data.ids.decompose(character, 0) {
+            Component::Character(_) => None,
+            component => Some(component),
+        }
+    }
+
+    /// Get the characters which contain a component at any level of their decomposition
+    /// e.g: 心 -> 想, 忘, 思...
+    ///
+    /// # Arguments
+    ///
+    /// * `component` - char
+    pub fn characters_containing(&self, component: char) -> Vec<char> {
+        let parents = &self.data.ids.parents;
+        let mut found = BTreeSet::new();
+        let mut visited = HashSet::from([component]);
+        let mut queue = vec![component];
+
+        while let Some(c) = queue.pop() {
+            for parent in parents.get(&c).into_iter().flatten() {
+                if visited.insert(*parent) {
+                    found.insert(*parent);
+                    queue.push(*parent);
+                }
+            }
+        }
+
+        found.into_iter().collect()
+    }
+
+    /// Break down each character of a word into its components with the meaning of each component
+    /// e.g: 想 = 相 (each other) + 心 (heart)
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    pub fn component_breakdown(&self, word: &str) -> Vec<CharacterBreakdown<'_>> {
+        word.chars()
+            .map(|character| CharacterBreakdown {
+                character,
+                components: self
+                    .decompose(character)
+                    .map(|component| component.parts())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|component| ComponentMeaning {
+                        component,
+                        word: self.dict.get(&component.to_string()),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// Remove the source annotations of a sequence e.g: "^⿱相心$(GTJKV)" or "⿱相心[GTJKV]" -> ⿱相心
+///
+/// # Arguments
+///
+/// * `sequence` - &str
+fn clean_sequence(sequence: &str) -> String {
+    let sequence = sequence.trim().trim_start_matches('^');
+    let sequence = sequence
+        .find(['$', '['])
+        .map_or(sequence, |idx| &sequence[..idx]);
+
+    sequence.to_string()
+}
+
+/// Get the number of parts arranged by an ideographic description character
+///
+/// # Arguments
+///
+/// * `c` - char
+fn operator_arity(c: char) -> Option<usize> {
+    match c {
+        '⿲' | '⿳' => Some(3),
+        '⿾' | '⿿' => Some(1),
+        '⿰' | '⿱' | '⿴'..='⿽' | '㇯' => Some(2),
+        _ => None,
+    }
+}
+
+/// Parse an Ideographic Description Sequence e.g: ⿱相心
+///
+/// # Arguments
+///
+/// * `sequence` - &str
+fn parse_sequence(sequence: &str) -> Option<Component> {
+    let chars = sequence.chars().collect::<Vec<_>>();
+    let mut cursor = 0;
+    let component = parse_component(&chars, &mut cursor)?;
+
+    (cursor == chars.len()).then_some(component)
+}
+
+/// Parse the component which starts at the cursor
+///
+/// # Arguments
+///
+/// * `chars` - &[char]
+/// * `cursor` - &mut usize
+fn parse_component(chars: &[char], cursor: &mut usize) -> Option<Component> {
+    let c = *chars.get(*cursor)?;
+    *cursor += 1;
+
+    // unencoded components are written as an entity e.g: &CDP-8BBF; or with a number e.g: {1}
+    let end = match c {
+        '&' => Some(';'),
+        '{' => Some('}'),
+        _ => None,
+    };
+
+    if let Some(end) = end {
+        let len = chars[*cursor..].iter().position(|c| *c == end)?;
+        let text = chars[*cursor - 1..=*cursor + len].iter().collect();
+        *cursor += len + 1;
+
+        return Some(Component::Unencoded(text));
+    }
+
+    let Some(arity) = operator_arity(c) else {
+        return Some(Component::Character(c));
+    };
+
+    let parts = (0..arity)
+        .map(|_| parse_component(chars, cursor))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(Component::Composition {
+        character: None,
+        operator: c,
+        parts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDS: &str = ";; sample of the cjkvi-ids file
+U+5FC3\t心\t心
+U+6728\t木\t木
+U+76EE\t目\t目
+U+76F8\t相\t⿰木目
+U+60F3\t想\t⿱相心[GTJKV]
+U+601D\t思\t^⿱田心$(GTJKV)\t⿱囟心[X]
+U+2E80\t⺀\t⿱&CDP-8BBF;{1}
+";
+
+    fn ids() -> Ids {
+        let mut ids = Ids::default();
+        ids.read(IDS.as_bytes()).unwrap();
+
+        ids
+    }
+
+    #[test]
+    fn expect_to_parse_sequence() {
+        assert_eq!(
+            parse_sequence("⿱&CDP-8BBF;{1}"),
+            Some(Component::Composition {
+                character: None,
+                operator: '⿱',
+                parts: vec![
+                    Component::Unencoded("&CDP-8BBF;".to_string()),
+                    Component::Unencoded("{1}".to_string())
+                ]
+            })
+        );
+        assert_eq!(clean_sequence("^⿱田心$(GTJKV)"), "⿱田心");
+        assert!(parse_sequence("⿱相").is_none());
+    }
+
+    #[test]
+    fn expect_to_decompose_character() {
+        let component = ids().decompose('想', 0);
+
+        assert_eq!(component.parts(), vec!['相', '心']);
+        assert!(component.contains('木'));
+        assert_eq!(
+            component,
+            Component::Composition {
+                character: Some('想'),
+                operator: '⿱',
+                parts: vec![
+                    Component::Composition {
+                        character: Some('相'),
+                        operator: '⿰',
+                        parts: vec![Component::Character('木'), Component::Character('目')]
+                    },
+                    Component::Character('心')
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn expect_to_find_characters_containing_component() {
+        let ids = ids();
+
+        assert_eq!(ids.parents.get(&'心').unwrap(), &vec!['想', '思']);
+        assert_eq!(ids.parents.get(&'木').unwrap(), &vec!['相']);
+    }
+
+    #[test]
+    fn expect_to_not_duplicate_parents_when_loaded_twice() {
+        let mut ids = ids();
+        ids.read(IDS.as_bytes()).unwrap();
+
+        assert_eq!(ids.parents.get(&'心').unwrap(), &vec!['想', '思']);
+        assert_eq!(ids.parents.get(&'目').unwrap(), &vec!['相']);
+    }
+}
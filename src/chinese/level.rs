@@ -0,0 +1,100 @@
+use crate::dictionary::{Chinese, Dictionary};
+use crate::error::DictionaryError;
+use crate::util;
+use crate::word::{Level, LevelSystem, WordParserResult};
+use std::io::BufRead;
+use std::path::PathBuf;
+
+impl Dictionary<Chinese> {
+    /// Load a vocabulary list such as the HSK or the TOCFL. Each line contains a word and its level
+    /// separated by a tab e.g: "喜歡<TAB>1"
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - PathBuf
+    /// * `system` - LevelSystem
+    pub fn load_levels(
+        &mut self,
+        path: PathBuf,
+        system: LevelSystem,
+    ) -> Result<(), DictionaryError> {
+        self.load_levels_from_reader(
+            util::open_reader(path, DictionaryError::ChineseDictionary)?,
+            system,
+        )
+    }
+
+    /// Load a vocabulary list from a reader
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - R
+    /// * `system` - LevelSystem
+    pub fn load_levels_from_reader<R: BufRead>(
+        &mut self,
+        reader: R,
+        system: LevelSystem,
+    ) -> Result<(), DictionaryError> {
+        for line in reader.lines() {
+            let line = line.map_err(|err| DictionaryError::ChineseDictionary(err.to_string()))?;
+            let Some((word, level)) = line.split_once('\t') else {
+                continue;
+            };
+
+            let Some(level) = Level::parse(system, level) else {
+                continue;
+            };
+
+            let levels = self.data.levels.entry(word.trim().to_string()).or_default();
+
+            // a word keeps its lowest level when it's listed multiple times
+            match levels.iter_mut().find(|l| l.system == system) {
+                Some(existing) => existing.level = existing.level.min(level.level),
+                None => levels.push(level),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the levels of a word. The traditional and the simplified forms of the word are both used
+    /// as the vocabulary lists are written with one of them (e.g: HSK in simplified, TOCFL in traditional)
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    pub fn levels(&self, word: &str) -> Vec<Level> {
+        let mut forms = vec![word];
+        if let Some(w) = self.dict.get(word) {
+            forms.extend(w.written.iter().map(String::as_str));
+        }
+
+        let mut levels: Vec<Level> = Vec::new();
+        for level in forms
+            .into_iter()
+            .filter_map(|form| self.data.levels.get(form))
+            .flatten()
+        {
+            if !levels.iter().any(|l| l.system == level.system) {
+                levels.push(*level);
+            }
+        }
+
+        levels
+    }
+
+    /// Tag the words of a parse result with their levels
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - &mut WordParserResult
+    pub(crate) fn tag_levels(&self, words: &mut WordParserResult) {
+        if self.data.levels.is_empty() {
+            return;
+        }
+
+        for (key, word) in words.iter_mut() {
+            word.levels = self.levels(key);
+        }
+    }
+}
@@ -1,12 +1,46 @@
-use crate::dictionary::{Chinese, Dictionary, Initializer, Lang};
+use crate::dictionary::{Chinese, Dictionary, Initializer, Lang, LanguageData, LanguageDictionary};
 use crate::error::DictionaryError;
 use crate::punctuation;
+use crate::registry::LanguageTag;
 use crate::util;
-use crate::word::{Word, WordParser, WordParserResult};
+use crate::word::{
+    Level, Reading, Romanizer, Sense, SentenceSegmenter, TextSegment, Word, WordParser,
+    WordParserResult, segment,
+};
 use dodo_zh::cedict::Item;
+use dodo_zh::variant::KeyVariant;
 use std::collections::{BTreeMap, HashMap};
+use std::io::BufRead;
 use std::marker::PhantomData;
 use std::path::PathBuf;
+use unihan::Character;
+
+pub mod collation;
+pub mod ids;
+mod level;
+pub mod pinyin;
+pub mod stroke;
+pub mod unihan;
+
+/// Longest word looked up in the dictionary when a sentence is segmented
+const MAX_WORD_LENGTH: usize = 16;
+
+/// Data loaded in addition to the cedict. Each of them is optional
+#[derive(Debug, Default)]
+pub struct ChineseData {
+    /// Characters of the Unihan database
+    characters: HashMap<char, Character>,
+    /// Ideographic Description Sequences of the characters
+    ids: ids::Ids,
+    /// Stroke order of the characters from Make Me a Hanzi
+    strokes: HashMap<char, stroke::StrokeOrder>,
+    /// Levels of the words in the vocabulary lists (e.g: HSK, TOCFL)
+    levels: HashMap<String, Vec<Level>>,
+}
+
+impl LanguageData for Chinese {
+    type Data = ChineseData;
+}
 
 impl Initializer<Chinese> for Dictionary<Chinese> {
     fn initialize(params: Lang) -> Result<Dictionary<Chinese>, DictionaryError> {
@@ -17,6 +51,7 @@ impl Initializer<Chinese> for Dictionary<Chinese> {
             dict: HashMap::default(),
             punctuation: p.chinese,
             params,
+            data: ChineseData::default(),
         })
     }
 
@@ -83,6 +118,13 @@ impl WordParser for Dictionary<Chinese> {
                     // multiple time. If we're unable to find the same character / word for multiple time
                     // then we're increasing the start_cursor & end_cursor in a hope that we'll match something later on...
                     if unmatched > 1 {
+                        // the character is skipped. Use the Unihan data as a gloss when it has been loaded
+                        if let Some(c) = chars.first()
+                            && let Some(character) = self.character(*c)
+                        {
+                            self.insert_word(&mut words, c.to_string(), character.to_word(*c));
+                        }
+
                         start_cursor += 1;
                         end_cursor += 1;
                     } else {
@@ -90,7 +132,7 @@ impl WordParser for Dictionary<Chinese> {
                             // Push the latest founded item in the Dictionary
                             self.insert_word(
                                 &mut words,
-                                step_found_word_str.clone(),
+                                std::mem::take(&mut step_found_word_str),
                                 step_found_word.clone(),
                             );
                             // if nothing can be found on the Dictionary then we move the start_cursor to end_cursor - 1
@@ -112,17 +154,177 @@ impl WordParser for Dictionary<Chinese> {
             }
         }
 
+        self.tag_levels(&mut words);
+
         words
     }
 }
 
+impl SentenceSegmenter for Dictionary<Chinese> {
+    fn segment_sentence<S: AsRef<str>>(&self, sentence: S) -> Vec<TextSegment> {
+        let mut segments = Vec::new();
+
+        for (run, punctuation) in segment::split_punctuation(sentence.as_ref(), &self.punctuation) {
+            if punctuation {
+                segments.push(TextSegment {
+                    text: run.to_string(),
+                    word: None,
+                    punctuation,
+                });
+                continue;
+            }
+
+            let chars = run.chars().collect::<Vec<_>>();
+            // characters which can't be matched are kept together in a single segment
+            let mut unknown = String::new();
+            let mut cursor = 0;
+            while cursor < chars.len() {
+                // the longest word of the dictionary starting at the cursor is used
+                let longest = (1..=MAX_WORD_LENGTH.min(chars.len() - cursor))
+                    .rev()
+                    .find_map(|len| {
+                        let text = chars[cursor..cursor + len].iter().collect::<String>();
+                        self.dict.get(&text).map(|word| (len, text, word.clone()))
+                    })
+                    .or_else(|| {
+                        let c = chars[cursor];
+                        self.character(c)
+                            .map(|character| (1, c.to_string(), character.to_word(c)))
+                    });
+
+                match longest {
+                    Some((len, text, mut word)) => {
+                        if !unknown.is_empty() {
+                            segments.push(TextSegment {
+                                text: std::mem::take(&mut unknown),
+                                ..Default::default()
+                            });
+                        }
+
+                        word.levels = self.levels(&text);
+                        segments.push(TextSegment {
+                            text,
+                            word: Some(word),
+                            punctuation: false,
+                        });
+                        cursor += len;
+                    }
+                    None => {
+                        unknown.push(chars[cursor]);
+                        cursor += 1;
+                    }
+                }
+            }
+
+            if !unknown.is_empty() {
+                segments.push(TextSegment {
+                    text: unknown,
+                    ..Default::default()
+                });
+            }
+        }
+
+        segments
+    }
+}
+
+impl Romanizer for Dictionary<Chinese> {
+    fn readings(&self, segment: &TextSegment) -> Vec<Reading> {
+        let Some(word) = &segment.word else {
+            return Vec::new();
+        };
+
+        // the cedict stores one syllable per pronunciation
+        word.pronunciations
+            .iter()
+            .map(|pronunciation| Reading {
+                text: pinyin::to_tone_marks(pronunciation),
+                tone: pinyin::parse_syllable(pronunciation)
+                    .map(|syllable| format!("tone{}", syllable.tone.unwrap_or(5))),
+            })
+            .collect()
+    }
+}
+
+impl Dictionary<Chinese> {
+    /// Load a Unihan data file. The Unihan database is split into multiple files, each of them can be loaded
+    /// e.g: Unihan_Readings.txt for the readings & the definition, Unihan_IRGSources.txt for the radical & strokes
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - PathBuf
+    pub fn load_unihan(&mut self, path: PathBuf) -> Result<(), DictionaryError> {
+        self.load_unihan_from_reader(util::open_reader(path, DictionaryError::ChineseDictionary)?)
+    }
+
+    /// Load Unihan data from a reader with the format of the Unihan database
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - R
+    pub fn load_unihan_from_reader<R: BufRead>(
+        &mut self,
+        reader: R,
+    ) -> Result<(), DictionaryError> {
+        let characters = &mut self.data.characters;
+        unihan::read_entries(reader, |c, field, value| {
+            characters.entry(c).or_default().set_field(field, value);
+        })?;
+
+        characters.retain(|_, character| !character.is_empty());
+
+        Ok(())
+    }
+
+    /// Get the Unihan information of a character. None is returned when no Unihan data has been loaded
+    ///
+    /// # Arguments
+    ///
+    /// * `character` - char
+    pub fn character(&self, character: char) -> Option<&Character> {
+        self.data.characters.get(&character)
+    }
+}
+
+impl LanguageDictionary for Dictionary<Chinese> {
+    fn tag(&self) -> LanguageTag {
+        match &self.params {
+            Lang::Chinese(KeyVariant::Simplified) => LanguageTag::ChineseSimplified,
+            _ => LanguageTag::ChineseTraditional,
+        }
+    }
+
+    fn lookup(&self, word: &str) -> Option<&Word> {
+        self.dict.get(word)
+    }
+
+    fn parse(&self, sentence: &str) -> WordParserResult {
+        self.parse_sentence_into_words(sentence)
+    }
+}
+
 impl From<Item> for Word {
     fn from(value: Item) -> Self {
+        // every translations of a cedict entry share the same pronunciation
+        let pronunciation = value.pinyin_tone_number.join(" ");
+        let senses = value
+            .translations
+            .iter()
+            .map(|translation| Sense {
+                pronunciation: pronunciation.clone(),
+                translation: translation.clone(),
+                parts_of_speech: Vec::new(),
+            })
+            .collect();
+
         Word {
             written: vec![value.traditional_character, value.simplified_character],
             pronunciations: value.pinyin_tone_number,
             translations: value.translations,
             count: 0,
+            parts_of_speech: Vec::new(),
+            senses,
+            levels: Vec::new(),
         }
     }
 }
@@ -155,7 +357,7 @@ mod tests {
         let res = DICTIONARY.parse_sentence_into_words("得");
         let dei = res.get("得").unwrap();
 
-        assert_eq!(dei.pronunciations.get(0).unwrap(), "dei3");
+        assert_eq!(dei.pronunciations.first().unwrap(), "dei3");
         assert_eq!(dei.pronunciations.last().unwrap(), "dei3");
     }
 
@@ -231,4 +433,154 @@ mod tests {
         assert_eq!(question_mark.count, 1);
         assert_eq!(question_mark.written.first().unwrap(), "嗎");
     }
+
+    #[test]
+    fn expect_to_use_dictionary_as_trait_object() {
+        let dictionary: &dyn LanguageDictionary = &*DICTIONARY;
+
+        assert_eq!(dictionary.tag(), LanguageTag::ChineseTraditional);
+        assert!(dictionary.lookup("你好").is_some());
+        assert_eq!(dictionary.parse("你好你好").get("你好").unwrap().count, 2);
+    }
+
+    #[test]
+    fn expect_to_search_word_by_pinyin() {
+        let index = DICTIONARY.pinyin_index(pinyin::FuzzyRules::default());
+        let res = index.search("nihao");
+
+        assert_eq!(res.first().unwrap().key, "你好");
+        assert!(res.first().unwrap().exact);
+    }
+
+    #[test]
+    fn expect_to_count_word_once_before_unmatched_character() {
+        for sentence in ["你丂好", "你丂", "丂你"] {
+            let words = DICTIONARY.parse_sentence_into_words(sentence);
+            assert_eq!(words.get("你").unwrap().count, 1, "{sentence}");
+        }
+
+        let words = DICTIONARY.parse_sentence_into_words("你丂好");
+        assert_eq!(words.get("好").unwrap().count, 1);
+        assert_eq!(words.len(), 2);
+    }
+
+    #[test]
+    fn expect_to_use_unihan_as_fallback_gloss() {
+        let mut dictionary =
+            super::Dictionary::<Chinese>::initialize(Lang::Chinese(Traditional)).unwrap();
+        dictionary.load(PathBuf::from("./cedict_ts.u8")).unwrap();
+        dictionary
+            .load_unihan_from_reader(
+                "U+2EBF0\tkMandarin\tyì\nU+2EBF0\tkDefinition\trare character\nU+2EBF0\tkTotalStrokes\t12\n"
+                    .as_bytes(),
+            )
+            .unwrap();
+
+        let character = dictionary.character('\u{2EBF0}').unwrap();
+        assert_eq!(character.total_strokes, Some(12));
+        assert_eq!(character.mandarin, vec!["yì"]);
+
+        let words = dictionary.parse_sentence_into_words("你好\u{2EBF0}");
+        assert_eq!(words.get("你好").unwrap().count, 1);
+
+        let gloss = words.get("\u{2EBF0}").unwrap();
+        assert_eq!(gloss.pronunciations, vec!["yi4"]);
+        assert_eq!(gloss.translations, vec!["rare character"]);
+    }
+
+    #[test]
+    fn expect_to_break_down_word_into_components() {
+        let mut dictionary =
+            super::Dictionary::<Chinese>::initialize(Lang::Chinese(Traditional)).unwrap();
+        dictionary.load(PathBuf::from("./cedict_ts.u8")).unwrap();
+        dictionary
+            .load_ids_from_reader("U+76F8\t相\t⿰木目\nU+60F3\t想\t⿱相心[GTJKV]\n".as_bytes())
+            .unwrap();
+
+        assert!(dictionary.decompose('心').is_none());
+        assert_eq!(dictionary.characters_containing('木'), vec!['想', '相']);
+
+        let breakdown = dictionary.component_breakdown("想");
+        let components = &breakdown.first().unwrap().components;
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].component, '相');
+        assert_eq!(components[1].component, '心');
+        assert!(
+            components[1]
+                .word
+                .unwrap()
+                .translations
+                .contains(&"heart".to_string())
+        );
+    }
+
+    #[test]
+    fn expect_to_tag_words_with_levels() {
+        use crate::word::{LevelReporter, LevelSystem};
+
+        let mut dictionary =
+            super::Dictionary::<Chinese>::initialize(Lang::Chinese(Traditional)).unwrap();
+        dictionary.load(PathBuf::from("./cedict_ts.u8")).unwrap();
+        dictionary
+            .load_levels_from_reader("你好\t1\n喜欢\t1\n开车\t3\n".as_bytes(), LevelSystem::Hsk2)
+            .unwrap();
+        dictionary
+            .load_levels_from_reader("喜歡\t2\n".as_bytes(), LevelSystem::Tocfl)
+            .unwrap();
+
+        let words = dictionary.parse_sentence_into_words("你好, 你喜歡開車嗎?");
+        let like = words.get("喜歡").unwrap();
+        assert_eq!(like.level(LevelSystem::Hsk2), Some(1));
+        assert_eq!(like.level(LevelSystem::Tocfl), Some(2));
+        assert_eq!(words.get("開車").unwrap().level(LevelSystem::Hsk2), Some(3));
+
+        let report = words.level_report(LevelSystem::Hsk2, 1);
+        assert_eq!(report.distribution.get(&3), Some(&1));
+        assert!(report.beyond_target > 0.0);
+    }
+
+    #[test]
+    fn expect_to_segment_sentence_in_order() {
+        let segments = DICTIONARY.segment_sentence("你好, 你喜歡開車嗎? ABC今天");
+        let texts = segments.iter().map(|s| s.text.as_str()).collect::<Vec<_>>();
+
+        assert_eq!(
+            texts,
+            vec![
+                "你好", ", ", "你", "喜歡", "開車", "嗎", "? ", "ABC", "今天"
+            ]
+        );
+        assert!(segments[1].punctuation);
+        assert_eq!(
+            segments[3].word.as_ref().unwrap().pronunciations,
+            vec!["xi3", "huan5"]
+        );
+        assert!(segments[7].is_unknown());
+        assert_eq!(
+            segments.iter().map(|s| s.text.as_str()).collect::<String>(),
+            "你好, 你喜歡開車嗎? ABC今天"
+        );
+    }
+
+    #[test]
+    fn expect_to_keep_parsed_words_of_sentence() {
+        // the parser stops growing a word at the first miss, hence 看起來 is split
+        let words = DICTIONARY.parse_sentence_into_words("去年今夜, 我看起來很高興");
+
+        assert_eq!(
+            words.keys().map(String::as_str).collect::<Vec<_>>(),
+            vec!["今夜", "來", "去年", "很", "我", "看", "起", "高興"]
+        );
+    }
+
+    #[test]
+    fn expect_to_segment_longest_word() {
+        // 看起 is not in the dictionary but 看起來 is
+        let segments = DICTIONARY.segment_sentence("我看起來很高興");
+
+        assert_eq!(
+            segments.iter().map(|s| s.text.as_str()).collect::<Vec<_>>(),
+            vec!["我", "看起來", "很", "高興"]
+        );
+    }
 }
@@ -0,0 +1,676 @@
+use crate::dictionary::{Chinese, Dictionary};
+use crate::word::Word;
+use std::collections::HashMap;
+
+/// Initials of the pinyin syllables. Two letters initials are listed first so that they're matched first
+const INITIALS: [&str; 23] = [
+    "zh", "ch", "sh", "b", "p", "m", "f", "d", "t", "n", "l", "g", "k", "h", "j", "q", "x", "r",
+    "z", "c", "s", "y", "w",
+];
+
+/// Finals of the pinyin syllables. The "ü" is written as "v"
+const FINALS: [&str; 37] = [
+    "a", "o", "e", "i", "u", "v", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "ong", "er",
+    "ia", "ie", "iao", "iu", "ian", "in", "iang", "ing", "iong", "ua", "uo", "uai", "ui", "uan",
+    "un", "uang", "ue", "ve", "van", "vn", "ueng",
+];
+
+/// Finals which can be written without an initial. The other finals are written with y or w e.g: "i" -> "yi"
+const STANDALONE_FINALS: [&str; 12] = [
+    "a", "o", "e", "ai", "ei", "ao", "ou", "an", "en", "ang", "eng", "er",
+];
+
+/// Length of the longest pinyin syllable e.g: "zhuang"
+const MAX_SYLLABLE_LEN: usize = 6;
+
+/// Rules used to match sounds which are commonly confused with each other
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FuzzyRules {
+    /// zh <-> z
+    pub zh_z: bool,
+    /// ch <-> c
+    pub ch_c: bool,
+    /// sh <-> s
+    pub sh_s: bool,
+    /// n <-> l
+    pub n_l: bool,
+    /// f <-> h
+    pub f_h: bool,
+    /// r <-> l
+    pub r_l: bool,
+    /// eng <-> en
+    pub eng_en: bool,
+    /// ing <-> in
+    pub ing_in: bool,
+    /// ang <-> an
+    pub ang_an: bool,
+}
+
+impl FuzzyRules {
+    /// Enable every fuzzy rules
+    pub fn all() -> Self {
+        FuzzyRules {
+            zh_z: true,
+            ch_c: true,
+            sh_s: true,
+            n_l: true,
+            f_h: true,
+            r_l: true,
+            eng_en: true,
+            ing_in: true,
+            ang_an: true,
+        }
+    }
+
+    /// Reduce a toneless syllable to a coarse sound shared by every syllable it can be confused with.
+    /// The sound may be shared by syllables which don't sound alike (e.g: n & r when both n <-> l and
+    /// r <-> l are enabled), the matches are checked with `sounds_alike`
+    ///
+    /// # Arguments
+    ///
+    /// * `syllable` - &str
+    fn canonicalize(&self, syllable: &str) -> String {
+        let (initial, final_) = split_initial(syllable);
+        let initial = match initial {
+            "zh" if self.zh_z => "z",
+            "ch" if self.ch_c => "c",
+            "sh" if self.sh_s => "s",
+            "n" | "l" if self.n_l => "l",
+            "r" | "l" if self.r_l => "l",
+            "f" if self.f_h => "h",
+            i => i,
+        };
+
+        let final_ = match final_ {
+            "eng" if self.eng_en => "en",
+            "ing" if self.ing_in => "in",
+            "ang" if self.ang_an => "an",
+            f => f,
+        };
+
+        format!("{initial}{final_}")
+    }
+
+    /// Check whether two toneless syllables are equal or can be confused with each other by one of the rules.
+    /// Each rule only matches its own pair of sounds
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - &str
+    /// * `b` - &str
+    fn sounds_alike(&self, a: &str, b: &str) -> bool {
+        let ((initial_a, final_a), (initial_b, final_b)) = (split_initial(a), split_initial(b));
+        let pairs = |x: &str, y: &str, rules: &[(bool, &str, &str)]| {
+            x == y
+                || rules.iter().any(|(enabled, first, second)| {
+                    *enabled && ((x, y) == (*first, *second) || (x, y) == (*second, *first))
+                })
+        };
+
+        pairs(
+            initial_a,
+            initial_b,
+            &[
+                (self.zh_z, "zh", "z"),
+                (self.ch_c, "ch", "c"),
+                (self.sh_s, "sh", "s"),
+                (self.n_l, "n", "l"),
+                (self.f_h, "f", "h"),
+                (self.r_l, "r", "l"),
+            ],
+        ) && pairs(
+            final_a,
+            final_b,
+            &[
+                (self.eng_en, "eng", "en"),
+                (self.ing_in, "ing", "in"),
+                (self.ang_an, "ang", "an"),
+            ],
+        )
+    }
+}
+
+/// A pinyin syllable without the tone mark. The tone is stored separately, None means that the tone
+/// hasn't been specified and that any tone is matched
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Syllable {
+    pub base: String,
+    pub tone: Option<u8>,
+}
+
+/// A word which has been found by a pinyin search
+#[derive(Debug, Clone)]
+pub struct PinyinMatch<'a> {
+    /// Key of the word in the dictionary
+    pub key: &'a str,
+    pub word: &'a Word,
+    /// Whether the word has been matched without using a fuzzy rule
+    pub exact: bool,
+}
+
+/// A word which has been indexed by its pronunciation
+struct IndexedWord<'a> {
+    key: &'a str,
+    word: &'a Word,
+    syllables: Vec<Syllable>,
+}
+
+/// Index over the pinyin of a chinese dictionary allowing to search a word by its pronunciation
+/// e.g: "nihao", "ni3 hao3" or "nǐ hǎo" -> 你好
+pub struct PinyinIndex<'a> {
+    rules: FuzzyRules,
+    words: Vec<IndexedWord<'a>>,
+    sounds: HashMap<String, Vec<usize>>,
+}
+
+impl<'a> PinyinIndex<'a> {
+    /// Build an index over the pronunciations of the given words
+    ///
+    /// # Arguments
+    ///
+    /// * `dict` - &HashMap<String, Word>
+    /// * `rules` - FuzzyRules
+    pub fn new(dict: &'a HashMap<String, Word>, rules: FuzzyRules) -> Self {
+        let mut words = Vec::new();
+        let mut sounds: HashMap<String, Vec<usize>> = HashMap::new();
+
+        for (key, word) in dict {
+            // words which contains a non pinyin pronunciation (latin letters, punctuations...) are skipped
+            let Some(syllables) = word
+                .pronunciations
+                .iter()
+                .map(|p| parse_syllable(p))
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+
+            if syllables.is_empty() {
+                continue;
+            }
+
+            sounds
+                .entry(sound_key(&rules, &syllables))
+                .or_default()
+                .push(words.len());
+
+            words.push(IndexedWord {
+                key,
+                word,
+                syllables,
+            });
+        }
+
+        PinyinIndex {
+            rules,
+            words,
+            sounds,
+        }
+    }
+
+    /// Search the words which pronunciation match the pinyin query. The query can be written with or without
+    /// tones (tone numbers or tone marks), with or without spaces between the syllables. Exact matches are
+    /// ranked before the matches made by a fuzzy rule, then shorter words are ranked first.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - &str
+    pub fn search(&self, query: &str) -> Vec<PinyinMatch<'a>> {
+        let Some(query) = parse_query(query) else {
+            return Vec::new();
+        };
+
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let Some(candidates) = self.sounds.get(&sound_key(&self.rules, &query)) else {
+            return Vec::new();
+        };
+
+        let mut results = candidates
+            .iter()
+            .map(|idx| &self.words[*idx])
+            .filter(|indexed| {
+                indexed.syllables.iter().zip(&query).all(|(s, q)| {
+                    (q.tone.is_none() || q.tone == s.tone)
+                        && self.rules.sounds_alike(&s.base, &q.base)
+                })
+            })
+            .map(|indexed| PinyinMatch {
+                key: indexed.key,
+                word: indexed.word,
+                exact: indexed
+                    .syllables
+                    .iter()
+                    .zip(&query)
+                    .all(|(s, q)| s.base == q.base),
+            })
+            .collect::<Vec<_>>();
+
+        results.sort_by(|a, b| {
+            b.exact
+                .cmp(&a.exact)
+                .then_with(|| a.key.chars().count().cmp(&b.key.chars().count()))
+                .then_with(|| a.key.cmp(b.key))
+        });
+
+        results
+    }
+}
+
+impl Dictionary<Chinese> {
+    /// Build an index over the pinyin of the dictionary
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - FuzzyRules
+    pub fn pinyin_index(&self, rules: FuzzyRules) -> PinyinIndex<'_> {
+        PinyinIndex::new(&self.dict, rules)
+    }
+}
+
+/// Parse a pinyin query into a list of syllables. Syllables can be separated by spaces or apostrophes.
+/// Unspaced syllables are segmented. A tone number applies to the syllable which precede it.
+///
+/// # Arguments
+///
+/// * `query` - &str
+pub fn parse_query(query: &str) -> Option<Vec<Syllable>> {
+    let mut syllables = Vec::new();
+    // letters of the current chunk with the tone mark found at the same position
+    let mut chunk: Vec<(char, Option<u8>)> = Vec::new();
+
+    let query = query.to_lowercase().replace("u:", "v");
+    for c in query.chars() {
+        match c {
+            '1'..='5' => {
+                let tone = c.to_digit(10).map(|t| t as u8);
+                let mut segmented = segment_chunk(&chunk)?;
+                // a tone number without any syllable is invalid e.g: "3"
+                segmented.last_mut()?.tone = tone;
+                syllables.extend(segmented);
+                chunk.clear();
+            }
+            c if c.is_whitespace() || c == '\'' || c == '-' => {
+                syllables.extend(segment_chunk(&chunk)?);
+                chunk.clear();
+            }
+            c => chunk.push(remove_tone_mark(c)?),
+        }
+    }
+
+    syllables.extend(segment_chunk(&chunk)?);
+
+    Some(syllables)
+}
+
+/// Split an unspaced pinyin text into syllables. The segmentation using the fewest syllables is used.
+/// e.g: "xian" -> ["xian"] rather than ["xi", "an"]
+///
+/// # Arguments
+///
+/// * `text` - &str
+pub fn segment(text: &str) -> Option<Vec<String>> {
+    if !text.is_ascii() {
+        return None;
+    }
+
+    // best[i] contains the number of syllables & the start of the last syllable of the text[..i]
+    let mut best: Vec<Option<(usize, usize)>> = vec![None; text.len() + 1];
+    best[0] = Some((0, 0));
+
+    for start in 0..text.len() {
+        let Some((count, _)) = best[start] else {
+            continue;
+        };
+
+        for end in (start + 1..=(start + MAX_SYLLABLE_LEN).min(text.len())).rev() {
+            if !is_syllable(&text[start..end]) {
+                continue;
+            }
+
+            match best[end] {
+                Some((c, _)) if c <= count + 1 => {}
+                _ => best[end] = Some((count + 1, start)),
+            }
+        }
+    }
+
+    let mut syllables = Vec::new();
+    let mut end = text.len();
+    while end > 0 {
+        let (_, start) = best[end]?;
+        syllables.push(text[start..end].to_string());
+        end = start;
+    }
+
+    syllables.reverse();
+
+    Some(syllables)
+}
+
+/// Segment a chunk of a query and attach the tone marks to the syllables which contains them
+///
+/// # Arguments
+///
+/// * `chunk` - &[(char, Option<u8>)]
+fn segment_chunk(chunk: &[(char, Option<u8>)]) -> Option<Vec<Syllable>> {
+    let text = chunk.iter().map(|(c, _)| c).collect::<String>();
+    let mut offset = 0;
+
+    segment(&text)?
+        .into_iter()
+        .map(|base| {
+            let tone = chunk[offset..offset + base.len()]
+                .iter()
+                .find_map(|(_, tone)| *tone);
+            offset += base.len();
+
+            Some(Syllable { base, tone })
+        })
+        .collect()
+}
+
+/// Parse a syllable written with a tone number such as the one used by the cedict e.g: "lu:4"
+///
+/// # Arguments
+///
+/// * `pinyin` - &str
+pub(crate) fn parse_syllable(pinyin: &str) -> Option<Syllable> {
+    let pinyin = pinyin.to_lowercase().replace("u:", "v").replace('ü', "v");
+    let (base, tone) = match pinyin.strip_suffix(|c: char| c.is_ascii_digit()) {
+        Some(base) => (base, pinyin[base.len()..].parse::<u8>().ok()),
+        None => (pinyin.as_str(), None),
+    };
+
+    if base.is_empty() || !base.chars().all(|c| c.is_ascii_lowercase()) {
+        return None;
+    }
+
+    Some(Syllable {
+        base: base.to_string(),
+        tone,
+    })
+}
+
+/// Convert a pinyin written with tone numbers to a pinyin with tone marks e.g: "ni3 hao3" -> "nǐ hǎo".
+/// Syllables which aren't pinyin are kept as is
+///
+/// # Arguments
+///
+/// * `pinyin` - &str
+pub fn to_tone_marks(pinyin: &str) -> String {
+    pinyin
+        .split_whitespace()
+        .map(|syllable| match parse_syllable(syllable) {
+            Some(s) => add_tone_mark(&s),
+            None => syllable.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Write a syllable with its tone mark. The mark is put on the a or the e, on the o of ou and
+/// on the last vowel otherwise
+///
+/// # Arguments
+///
+/// * `syllable` - &Syllable
+fn add_tone_mark(syllable: &Syllable) -> String {
+    let base = &syllable.base;
+    let position = base
+        .find('a')
+        .or_else(|| base.find('e'))
+        .or_else(|| base.find("ou"))
+        .or_else(|| base.rfind(['i', 'o', 'u', 'v']));
+
+    base.char_indices()
+        .map(|(idx, c)| match (Some(idx) == position, syllable.tone) {
+            (true, Some(tone @ 1..=4)) => mark_vowel(c, tone),
+            _ if c == 'v' => 'ü',
+            _ => c,
+        })
+        .collect()
+}
+
+/// Get the vowel with the tone mark of a tone
+///
+/// # Arguments
+///
+/// * `vowel` - char
+/// * `tone` - u8
+fn mark_vowel(vowel: char, tone: u8) -> char {
+    let marks = match vowel {
+        'a' => ['ā', 'á', 'ǎ', 'à'],
+        'e' => ['ē', 'é', 'ě', 'è'],
+        'i' => ['ī', 'í', 'ǐ', 'ì'],
+        'o' => ['ō', 'ó', 'ǒ', 'ò'],
+        'u' => ['ū', 'ú', 'ǔ', 'ù'],
+        'v' => ['ǖ', 'ǘ', 'ǚ', 'ǜ'],
+        _ => return vowel,
+    };
+
+    marks[tone as usize - 1]
+}
+
+/// Replace a vowel with a tone mark by the vowel and its tone
+///
+/// # Arguments
+///
+/// * `c` - char
+fn remove_tone_mark(c: char) -> Option<(char, Option<u8>)> {
+    let res = match c {
+        'ā' => ('a', Some(1)),
+        'á' => ('a', Some(2)),
+        'ǎ' => ('a', Some(3)),
+        'à' => ('a', Some(4)),
+        'ē' => ('e', Some(1)),
+        'é' => ('e', Some(2)),
+        'ě' => ('e', Some(3)),
+        'è' => ('e', Some(4)),
+        'ī' => ('i', Some(1)),
+        'í' => ('i', Some(2)),
+        'ǐ' => ('i', Some(3)),
+        'ì' => ('i', Some(4)),
+        'ō' => ('o', Some(1)),
+        'ó' => ('o', Some(2)),
+        'ǒ' => ('o', Some(3)),
+        'ò' => ('o', Some(4)),
+        'ū' => ('u', Some(1)),
+        'ú' => ('u', Some(2)),
+        'ǔ' => ('u', Some(3)),
+        'ù' => ('u', Some(4)),
+        'ǖ' => ('v', Some(1)),
+        'ǘ' => ('v', Some(2)),
+        'ǚ' => ('v', Some(3)),
+        'ǜ' => ('v', Some(4)),
+        'ü' => ('v', None),
+        c if c.is_ascii_lowercase() => (c, None),
+        _ => return None,
+    };
+
+    Some(res)
+}
+
+/// Split a syllable into its initial and its final
+///
+/// # Arguments
+///
+/// * `syllable` - &str
+fn split_initial(syllable: &str) -> (&str, &str) {
+    INITIALS
+        .iter()
+        .find_map(|initial| {
+            syllable
+                .strip_prefix(initial)
+                .filter(|final_| !final_.is_empty())
+                .map(|final_| (&syllable[..initial.len()], final_))
+        })
+        .unwrap_or(("", syllable))
+}
+
+/// Check whether the text is a valid toneless pinyin syllable
+///
+/// # Arguments
+///
+/// * `text` - &str
+fn is_syllable(text: &str) -> bool {
+    let (initial, final_) = split_initial(text);
+    match (initial, final_) {
+        (_, "er") => initial.is_empty(),
+        ("", f) => STANDALONE_FINALS.contains(&f),
+        (_, f) => FINALS.contains(&f),
+    }
+}
+
+/// Create the key under which a list of syllables is indexed
+///
+/// # Arguments
+///
+/// * `rules` - &FuzzyRules
+/// * `syllables` - &[Syllable]
+fn sound_key(rules: &FuzzyRules, syllables: &[Syllable]) -> String {
+    syllables
+        .iter()
+        .map(|s| rules.canonicalize(&s.base))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(pronunciations: &[&str]) -> Word {
+        Word {
+            pronunciations: pronunciations.iter().map(|p| p.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn dict() -> HashMap<String, Word> {
+        HashMap::from([
+            ("你好".to_string(), word(&["ni3", "hao3"])),
+            ("你".to_string(), word(&["ni3"])),
+            ("中".to_string(), word(&["zhong1"])),
+            ("難".to_string(), word(&["nan2"])),
+            ("女".to_string(), word(&["nu:3"])),
+            ("西安".to_string(), word(&["Xi1", "an1"])),
+            ("先".to_string(), word(&["xian1"])),
+        ])
+    }
+
+    #[test]
+    fn expect_to_segment_unspaced_pinyin() {
+        assert_eq!(segment("nihao").unwrap(), vec!["ni", "hao"]);
+        assert_eq!(segment("xian").unwrap(), vec!["xian"]);
+        assert_eq!(segment("zhongguoren").unwrap(), vec!["zhong", "guo", "ren"]);
+        assert!(segment("xyz").is_none());
+        assert!(segment("i").is_none());
+        assert!(segment("ong").is_none());
+        assert_eq!(segment("an").unwrap(), vec!["an"]);
+    }
+
+    #[test]
+    fn expect_to_parse_query_with_tones() {
+        let expected = vec![
+            Syllable {
+                base: "ni".to_string(),
+                tone: Some(3),
+            },
+            Syllable {
+                base: "hao".to_string(),
+                tone: Some(3),
+            },
+        ];
+
+        assert_eq!(parse_query("ni3 hao3").unwrap(), expected);
+        assert_eq!(parse_query("ni3hao3").unwrap(), expected);
+        assert_eq!(parse_query("nǐhǎo").unwrap(), expected);
+        assert_eq!(parse_query("xi'an").unwrap().len(), 2);
+        assert_eq!(parse_query("nihao3").unwrap()[0].tone, None);
+    }
+
+    #[test]
+    fn expect_to_write_pinyin_with_tone_marks() {
+        assert_eq!(to_tone_marks("ni3 hao3"), "nǐ hǎo");
+        assert_eq!(to_tone_marks("xi3 huan5"), "xǐ huan");
+        assert_eq!(to_tone_marks("lu:4 dou4"), "lǜ dòu");
+        assert_eq!(to_tone_marks("gui4 Bei3 jing1"), "guì běi jīng");
+        assert_eq!(to_tone_marks("A A4"), "a à");
+    }
+
+    #[test]
+    fn expect_to_search_by_pinyin() {
+        let dict = dict();
+        let index = PinyinIndex::new(&dict, FuzzyRules::default());
+
+        for query in ["nihao", "ni hao", "ni3 hao3", "nǐ hǎo", "NiHao"] {
+            assert_eq!(index.search(query)[0].key, "你好");
+        }
+
+        assert!(index.search("ni4 hao3").is_empty());
+        assert_eq!(index.search("nü3")[0].key, "女");
+        assert_eq!(index.search("xi'an")[0].key, "西安");
+        assert_eq!(index.search("xian")[0].key, "先");
+    }
+
+    #[test]
+    fn expect_to_search_with_fuzzy_rules() {
+        let dict = dict();
+
+        let strict = PinyinIndex::new(&dict, FuzzyRules::default());
+        assert!(strict.search("zong").is_empty());
+        assert!(strict.search("lan2").is_empty());
+
+        let fuzzy = PinyinIndex::new(
+            &dict,
+            FuzzyRules {
+                zh_z: true,
+                n_l: true,
+                ..Default::default()
+            },
+        );
+
+        let res = fuzzy.search("zong");
+        assert_eq!(res[0].key, "中");
+        assert!(!res[0].exact);
+
+        assert_eq!(fuzzy.search("lan2")[0].key, "難");
+        assert!(fuzzy.search("nan2")[0].exact);
+    }
+
+    #[test]
+    fn expect_each_fuzzy_rule_to_match_its_own_pair() {
+        let dict = HashMap::from([
+            ("嫩".to_string(), word(&["nen4"])),
+            ("任".to_string(), word(&["ren4"])),
+            ("楞".to_string(), word(&["leng4"])),
+            ("香".to_string(), word(&["xiang1"])),
+            ("先".to_string(), word(&["xian1"])),
+        ]);
+
+        let rules = FuzzyRules::all();
+        let index = PinyinIndex::new(&dict, rules);
+
+        let keys = |query: &str| {
+            let mut keys = index
+                .search(query)
+                .iter()
+                .map(|m| m.key)
+                .collect::<Vec<_>>();
+            keys.sort();
+            keys
+        };
+
+        // n and r both sound like l but not like each other
+        assert_eq!(keys("ren4"), vec!["任", "楞"]);
+        assert_eq!(keys("nen4"), vec!["嫩", "楞"]);
+        assert_eq!(keys("len4").len(), 3);
+        // iang is not folded into ian
+        assert_eq!(keys("xian1"), vec!["先"]);
+        assert!(rules.sounds_alike("zhang", "zan"));
+        assert!(!rules.sounds_alike("xiang", "xian"));
+    }
+}
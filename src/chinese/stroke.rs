@@ -0,0 +1,154 @@
+use crate::dictionary::{Chinese, Dictionary};
+use crate::error::DictionaryError;
+use crate::util;
+use serde::{Deserialize, Serialize};
+use std::io::BufRead;
+use std::path::PathBuf;
+
+/// Etymology of a character as described by Make Me a Hanzi
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Etymology {
+    /// Kind of the character e.g: ideographic, pictographic, pictophonetic
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Short explanation of the origin of the character
+    pub hint: Option<String>,
+    /// Component giving the sound of a pictophonetic character
+    pub phonetic: Option<String>,
+    /// Component giving the meaning of a pictophonetic character
+    pub semantic: Option<String>,
+}
+
+/// Strokes of a character used to practice its writing
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct StrokeOrder {
+    /// SVG path of each stroke in the writing order
+    pub strokes: Vec<String>,
+    /// Points of the median line of each stroke. It gives the direction of the stroke
+    pub medians: Vec<Vec<[i32; 2]>>,
+    pub etymology: Option<Etymology>,
+}
+
+/// Stroke order of a character of a word. The stroke order is None when the character is unknown
+#[derive(Debug, Clone)]
+pub struct CharacterStrokeOrder<'a> {
+    pub character: char,
+    pub stroke_order: Option<&'a StrokeOrder>,
+}
+
+/// A line of the graphics.txt or the dictionary.txt files of Make Me a Hanzi. Each file only contains
+/// a part of the fields
+#[derive(Debug, Deserialize)]
+struct HanziItem {
+    character: char,
+    #[serde(default)]
+    strokes: Vec<String>,
+    #[serde(default)]
+    medians: Vec<Vec<[i32; 2]>>,
+    #[serde(default)]
+    etymology: Option<Etymology>,
+}
+
+impl Dictionary<Chinese> {
+    /// Load a file of Make Me a Hanzi. Both the graphics.txt which contains the strokes and the
+    /// dictionary.txt which contains the etymology can be loaded
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - PathBuf
+    pub fn load_stroke_order(&mut self, path: PathBuf) -> Result<(), DictionaryError> {
+        self.load_stroke_order_from_reader(util::open_reader(
+            path,
+            DictionaryError::ChineseDictionary,
+        )?)
+    }
+
+    /// Load the stroke order from a reader with the format of Make Me a Hanzi (one JSON object per line)
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - R
+    pub fn load_stroke_order_from_reader<R: BufRead>(
+        &mut self,
+        reader: R,
+    ) -> Result<(), DictionaryError> {
+        for line in reader.lines() {
+            let line = line.map_err(|err| DictionaryError::ChineseDictionary(err.to_string()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let item: HanziItem = serde_json::from_str(&line)
+                .map_err(|err| DictionaryError::ChineseDictionary(err.to_string()))?;
+
+            let stroke_order = self.data.strokes.entry(item.character).or_default();
+            if !item.strokes.is_empty() {
+                stroke_order.strokes = item.strokes;
+                stroke_order.medians = item.medians;
+            }
+
+            if item.etymology.is_some() {
+                stroke_order.etymology = item.etymology;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the stroke order of a character. None is returned when the character is unknown
+    ///
+    /// # Arguments
+    ///
+    /// * `character` - char
+    pub fn stroke_order(&self, character: char) -> Option<&StrokeOrder> {
+        self.data.strokes.get(&character)
+    }
+
+    /// Get the stroke order of every character of a word
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    pub fn word_stroke_order(&self, word: &str) -> Vec<CharacterStrokeOrder<'_>> {
+        word.chars()
+            .map(|character| CharacterStrokeOrder {
+                character,
+                stroke_order: self.stroke_order(character),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dictionary::{Chinese, Dictionary, Initializer, Lang};
+    use dodo_zh::variant::KeyVariant;
+
+    const GRAPHICS: &str = r#"{"character":"人","strokes":["M 520 710 Q 540 690 530 650","M 490 430 Q 560 300 860 100"],"medians":[[[523,710],[535,650]],[[490,430],[860,100]]]}"#;
+    const DICTIONARY: &str = r#"{"character":"人","definition":"man, person","pinyin":["rén"],"decomposition":"？","etymology":{"type":"pictographic","hint":"A man"},"radical":"人","matches":[]}"#;
+
+    #[test]
+    fn expect_to_load_stroke_order() {
+        let mut dictionary =
+            Dictionary::<Chinese>::initialize(Lang::Chinese(KeyVariant::Traditional)).unwrap();
+        dictionary
+            .load_stroke_order_from_reader(GRAPHICS.as_bytes())
+            .unwrap();
+        dictionary
+            .load_stroke_order_from_reader(DICTIONARY.as_bytes())
+            .unwrap();
+
+        let stroke_order = dictionary.stroke_order('人').unwrap();
+        assert_eq!(stroke_order.strokes.len(), 2);
+        assert_eq!(stroke_order.medians[1], vec![[490, 430], [860, 100]]);
+        assert_eq!(
+            stroke_order.etymology.as_ref().unwrap().hint.as_deref(),
+            Some("A man")
+        );
+
+        let word = dictionary.word_stroke_order("人口");
+        assert!(word[0].stroke_order.is_some());
+        assert_eq!(word[1].character, '口');
+        assert!(word[1].stroke_order.is_none());
+    }
+}
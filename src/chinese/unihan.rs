@@ -0,0 +1,211 @@
+use super::pinyin;
+use crate::error::DictionaryError;
+use crate::word::{Sense, Word};
+use serde::Serialize;
+use std::io::BufRead;
+
+/// Prefix of the code points in the Unihan database and the IDS file e.g: U+4E00
+pub(crate) const CODE_POINT_PREFIX: &str = "U+";
+
+/// Tone used by the cedict for the neutral tone
+const NEUTRAL_TONE: u8 = 5;
+
+/// Information of a single character found in the Unihan database. Fields are only filled when the
+/// matching data file has been loaded e.g: the readings are in Unihan_Readings.txt
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Character {
+    pub radical_stroke: Option<RadicalStroke>,
+    pub total_strokes: Option<u32>,
+    /// Mandarin readings with tone marks e.g: nǐ
+    pub mandarin: Vec<String>,
+    /// Cantonese readings in jyutping e.g: nei5
+    pub cantonese: Vec<String>,
+    /// Short english definition of the character
+    pub definition: Option<String>,
+}
+
+impl Character {
+    /// Set the value of a Unihan field. Fields which aren't used are ignored
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - &str
+    /// * `value` - &str
+    pub(crate) fn set_field(&mut self, field: &str, value: &str) {
+        match field {
+            "kRSUnicode" => self.radical_stroke = RadicalStroke::parse(value),
+            "kTotalStrokes" => {
+                self.total_strokes = value.split_whitespace().next().and_then(|v| v.parse().ok())
+            }
+            "kMandarin" => self.mandarin = value.split_whitespace().map(String::from).collect(),
+            "kCantonese" => self.cantonese = value.split_whitespace().map(String::from).collect(),
+            "kDefinition" => self.definition = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    /// Whether one of the field of the character has been set
+    pub(crate) fn is_empty(&self) -> bool {
+        *self == Character::default()
+    }
+
+    /// Create a word from the character. It's used as a gloss for the characters which aren't in the cedict.
+    /// The mandarin readings are converted to the tone numbers used by the cedict e.g: nǐ -> ni3
+    ///
+    /// # Arguments
+    ///
+    /// * `character` - char
+    pub fn to_word(&self, character: char) -> Word {
+        let pronunciations = self
+            .mandarin
+            .iter()
+            .take(1)
+            .filter_map(|reading| pinyin::parse_query(reading))
+            .flatten()
+            .map(|s| {
+                format!(
+                    "{}{}",
+                    s.base.replace('v', "u:"),
+                    s.tone.unwrap_or(NEUTRAL_TONE)
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let senses = self
+            .definition
+            .iter()
+            .map(|definition| Sense {
+                pronunciation: pronunciations.join(" "),
+                translation: definition.clone(),
+                parts_of_speech: Vec::new(),
+            })
+            .collect();
+
+        Word {
+            written: vec![character.to_string()],
+            pronunciations,
+            translations: self.definition.iter().cloned().collect(),
+            count: 0,
+            parts_of_speech: Vec::new(),
+            senses,
+            levels: Vec::new(),
+        }
+    }
+}
+
+/// Radical and residual strokes of a character as defined by the kRSUnicode field e.g: 9.5 -> 你
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub struct RadicalStroke {
+    /// Number of the Kangxi radical
+    pub radical: u32,
+    /// Whether the character uses the simplified form of the radical e.g: 120' -> 纟
+    pub simplified: bool,
+    /// Number of strokes without the radical
+    pub residual_strokes: i32,
+}
+
+impl RadicalStroke {
+    /// Parse the first value of a kRSUnicode field e.g: "120'.3 120.3"
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - &str
+    pub fn parse(value: &str) -> Option<Self> {
+        let (radical, strokes) = value.split_whitespace().next()?.split_once('.')?;
+        let simplified = radical.ends_with('\'');
+
+        Some(RadicalStroke {
+            radical: radical.trim_end_matches('\'').parse().ok()?,
+            simplified,
+            residual_strokes: strokes.parse().ok()?,
+        })
+    }
+}
+
+/// Read the entries of a Unihan data file. Each entry is composed of a character, a field and a value
+/// e.g: "U+4F60<TAB>kRSUnicode<TAB>9.5"
+///
+/// # Arguments
+///
+/// * `reader` - R
+/// * `f` - F
+pub(crate) fn read_entries<R, F>(reader: R, mut f: F) -> Result<(), DictionaryError>
+where
+    R: BufRead,
+    F: FnMut(char, &str, &str),
+{
+    for line in reader.lines() {
+        let line = line.map_err(|err| DictionaryError::ChineseDictionary(err.to_string()))?;
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let mut parts = line.splitn(3, '\t');
+        let (Some(code_point), Some(field), Some(value)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+
+        let Some(character) = code_point
+            .strip_prefix(CODE_POINT_PREFIX)
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .and_then(char::from_u32)
+        else {
+            continue;
+        };
+
+        f(character, field, value);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_parse_radical_stroke() {
+        assert_eq!(
+            RadicalStroke::parse("120'.3 120.3"),
+            Some(RadicalStroke {
+                radical: 120,
+                simplified: true,
+                residual_strokes: 3
+            })
+        );
+        assert_eq!(RadicalStroke::parse("9.5").unwrap().radical, 9);
+        assert!(RadicalStroke::parse("").is_none());
+    }
+
+    #[test]
+    fn expect_to_create_word_from_character() {
+        let mut character = Character::default();
+        character.set_field("kMandarin", "lǜ");
+        character.set_field("kDefinition", "green");
+        character.set_field("kTotalStrokes", "11");
+
+        let word = character.to_word('綠');
+        assert_eq!(character.total_strokes, Some(11));
+        assert_eq!(word.pronunciations, vec!["lu:4"]);
+        assert_eq!(word.translations, vec!["green"]);
+    }
+
+    #[test]
+    fn expect_to_read_entries() {
+        let data = "# comment\nU+4F60\tkRSUnicode\t9.5\nU+4F60\tkTotalStrokes\t7\ninvalid line\n";
+
+        let mut entries = Vec::new();
+        read_entries(data.as_bytes(), |c, field, value| {
+            entries.push((c, field.to_string(), value.to_string()))
+        })
+        .unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[0],
+            ('你', "kRSUnicode".to_string(), "9.5".to_string())
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use crate::error::DictionaryError;
+use crate::export::{self, ExportOptions, Format};
+use crate::word::{Word, WordParserResult};
+use std::io::Write;
+
+/// A collator defines the order used to sort the words of a dictionary
+pub trait Collator {
+    /// Key used to compare two words
+    type Key: Ord;
+
+    /// Compute the key used to sort a word
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - &str
+    /// * `word` - &Word
+    fn sort_key(&self, key: &str, word: &Word) -> Self::Key;
+}
+
+/// Sort the words of a parse result with a collator rather than by code points
+pub trait SortedWords {
+    /// Get the words sorted by the collator
+    ///
+    /// # Arguments
+    ///
+    /// * `collator` - &C
+    fn sorted_by<C: Collator>(&self, collator: &C) -> Vec<(&str, &Word)>;
+
+    /// Export the words to CSV in the order defined by the collator
+    ///
+    /// # Arguments
+    ///
+    /// * `collator` - &C
+    fn to_csv_sorted_by<C: Collator>(&self, collator: &C) -> Result<String, DictionaryError>;
+
+    /// Write the words to a writer in the order defined by the collator
+    ///
+    /// # Arguments
+    ///
+    /// * `collator` - &C
+    /// * `format` - Format
+    /// * `options` - &ExportOptions
+    /// * `writer` - &mut W
+    fn write_sorted_by<C: Collator, W: Write>(
+        &self,
+        collator: &C,
+        format: Format,
+        options: &ExportOptions,
+        writer: &mut W,
+    ) -> Result<(), DictionaryError>;
+}
+
+impl SortedWords for WordParserResult {
+    fn sorted_by<C: Collator>(&self, collator: &C) -> Vec<(&str, &Word)> {
+        let mut words = self
+            .iter()
+            .map(|(key, word)| (key.as_str(), word))
+            .collect::<Vec<_>>();
+
+        words.sort_by_cached_key(|(key, word)| (collator.sort_key(key, word), key.to_string()));
+
+        words
+    }
+
+    fn to_csv_sorted_by<C: Collator>(&self, collator: &C) -> Result<String, DictionaryError> {
+        export::export_words(
+            self.sorted_by(collator),
+            Format::Csv,
+            &ExportOptions::word_fields(),
+        )
+    }
+
+    fn write_sorted_by<C: Collator, W: Write>(
+        &self,
+        collator: &C,
+        format: Format,
+        options: &ExportOptions,
+        writer: &mut W,
+    ) -> Result<(), DictionaryError> {
+        export::write_words(self.sorted_by(collator), format, options, writer)
+    }
+}
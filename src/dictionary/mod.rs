@@ -1,6 +1,11 @@
-use crate::{error::DictionaryError, word::Word};
+use crate::registry::LanguageTag;
+use crate::{
+    error::DictionaryError,
+    word::{Word, WordParserResult},
+};
 use chamkho::Wordcut;
 use dodo_zh::variant::KeyVariant;
+use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, marker::PhantomData, path::PathBuf};
 
 pub enum Lang {
@@ -8,6 +13,13 @@ pub enum Lang {
     Laotian(Option<Box<Wordcut>>),
 }
 
+/// Language of a dictionary. Used to tag words when multiple dictionaries are used together
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Language {
+    Chinese,
+    Laotian,
+}
+
 // Blanket state implementation used to initialize Dictionary for different language.
 /// A Chinese dictionary
 pub struct Chinese;
@@ -19,11 +31,25 @@ pub struct Laotian;
 /// done through the usage of the PhantomData
 ///
 /// The Dictionary struct usually implements the Initializer trait.
-pub struct Dictionary<T> {
+pub struct Dictionary<T: LanguageData> {
     pub _lang: PhantomData<T>,
     pub dict: HashMap<String, Word>,
     pub punctuation: Vec<String>,
     pub params: Lang,
+    /// Data which is only loaded by the dictionary of the language (e.g: Unihan for chinese)
+    pub(crate) data: T::Data,
+}
+
+impl<T: LanguageData> Dictionary<T> {
+    /// Get the data which is specific to the language of the dictionary (e.g: Unihan for chinese)
+    pub fn data(&self) -> &T::Data {
+        &self.data
+    }
+}
+
+/// Data which is specific to the language of a dictionary
+pub trait LanguageData {
+    type Data: Default;
 }
 
 pub(crate) trait Initializer<T> {
@@ -43,3 +69,24 @@ pub(crate) trait Initializer<T> {
     /// * `path` - PathBuf
     fn load(&mut self, path: PathBuf) -> Result<(), DictionaryError>;
 }
+
+/// Object safe version of a dictionary. It allows to hold dictionaries of different languages
+/// in the same collection (e.g: Vec<Box<dyn LanguageDictionary>>)
+pub trait LanguageDictionary: Send + Sync {
+    /// Get the language tag of the dictionary
+    fn tag(&self) -> LanguageTag;
+
+    /// Get the definition of a word
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    fn lookup(&self, word: &str) -> Option<&Word>;
+
+    /// Parse a sentence into words. See [`crate::word::WordParser::parse_sentence_into_words`]
+    ///
+    /// # Arguments
+    ///
+    /// * `sentence` - &str
+    fn parse(&self, sentence: &str) -> WordParserResult;
+}
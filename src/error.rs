@@ -7,6 +7,7 @@ pub enum DictionaryError {
     ChineseDictionary(String),
     LaoDictionary(String),
     Puncutation(String),
+    Language(String),
 }
 
 impl std::fmt::Display for DictionaryError {
@@ -21,6 +22,7 @@ impl std::fmt::Display for DictionaryError {
             DictionaryError::Puncutation(msg) => {
                 write!(f, "Unable to retrieve the punctuation: {msg}")
             }
+            DictionaryError::Language(msg) => write!(f, "Unsupported language: {msg}"),
         }
     }
 }
@@ -1,11 +1,467 @@
 use crate::error::DictionaryError;
+use crate::word::Word;
 use csv::Writer;
+use serde::ser::SerializeMap;
 use serde::{Serialize, Serializer};
+use std::collections::HashMap;
+use std::io::Write;
+
+/// Default separator of the items of a list when a list is exported as a single cell. A comma is not
+/// used as the translations may contain commas
+const DEFAULT_LIST_DELIMITER: &str = "; ";
+
+/// Character which escapes the separator in the items of a list e.g: "to be fond of\, keen"
+pub(crate) const LIST_ESCAPE: char = '\\';
 
 pub trait Export {
     /// Export a a type to CSV
     ///     - Definitions: In this case this will return a csv of definitions
-    fn to_csv(&self) -> Result<String, DictionaryError>;
+    fn to_csv(&self) -> Result<String, DictionaryError> {
+        self.export(Format::Csv, &ExportOptions::word_fields())
+    }
+
+    /// Write a type to a writer in the given format with the given columns. Rows are written one
+    /// by one without buffering the whole export in memory. The method is generic so `Export` can't
+    /// be used as `dyn Export`
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Format
+    /// * `options` - &ExportOptions
+    /// * `writer` - &mut W
+    fn write_to<W: Write>(
+        &self,
+        format: Format,
+        options: &ExportOptions,
+        writer: &mut W,
+    ) -> Result<(), DictionaryError>;
+
+    /// Export a type to the given format with the given columns
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Format
+    /// * `options` - &ExportOptions
+    fn export(&self, format: Format, options: &ExportOptions) -> Result<String, DictionaryError> {
+        let mut buffer = Vec::new();
+        self.write_to(format, options, &mut buffer)?;
+
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    /// Export a type to JSON. Lists are kept as arrays
+    fn to_json(&self) -> Result<String, DictionaryError> {
+        self.export(Format::Json, &ExportOptions::default())
+    }
+
+    /// Export a type to a tab separated file
+    fn to_tsv(&self) -> Result<String, DictionaryError> {
+        self.export(Format::Tsv, &ExportOptions::default())
+    }
+
+    /// Export a type to a markdown table
+    fn to_markdown(&self) -> Result<String, DictionaryError> {
+        self.export(Format::Markdown, &ExportOptions::default())
+    }
+}
+
+/// Output format of an export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Json,
+    Tsv,
+    Markdown,
+}
+
+/// Every column which can be exported
+const COLUMNS: [Column; 7] = [
+    Column::Key,
+    Column::Written,
+    Column::Pronunciations,
+    Column::Translations,
+    Column::Count,
+    Column::PartsOfSpeech,
+    Column::Levels,
+];
+
+/// A field of a word which can be exported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Column {
+    /// Key of the word in the dictionary
+    Key,
+    Written,
+    Pronunciations,
+    Translations,
+    Count,
+    PartsOfSpeech,
+    Levels,
+}
+
+impl Column {
+    /// Get the default header of the column
+    pub fn name(&self) -> &'static str {
+        match self {
+            Column::Key => "key",
+            Column::Written => "written",
+            Column::Pronunciations => "pronunciations",
+            Column::Translations => "translations",
+            Column::Count => "count",
+            Column::PartsOfSpeech => "parts_of_speech",
+            Column::Levels => "levels",
+        }
+    }
+
+    /// Get the value of the column for a word
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - &str
+    /// * `word` - &Word
+    fn value(&self, key: &str, word: &Word) -> Cell {
+        match self {
+            Column::Key => Cell::Text(key.to_string()),
+            Column::Written => Cell::List(word.written.clone()),
+            Column::Pronunciations => Cell::List(word.pronunciations.clone()),
+            Column::Translations => Cell::List(word.translations.clone()),
+            Column::Count => Cell::Number(word.count),
+            Column::PartsOfSpeech => Cell::List(
+                word.parts_of_speech
+                    .iter()
+                    .map(|pos| pos.as_str().to_string())
+                    .collect(),
+            ),
+            Column::Levels => Cell::List(word.levels.iter().map(|l| l.to_string()).collect()),
+        }
+    }
+}
+
+/// Value of a column for a word
+enum Cell {
+    Text(String),
+    List(Vec<String>),
+    Number(i128),
+}
+
+impl Cell {
+    /// Convert the value to a single text. Lists are joined with the delimiter. The separator is escaped
+    /// in the items when the options escape the lists so that the list can be imported back
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - &ExportOptions
+    fn to_text(&self, options: &ExportOptions) -> String {
+        let separator = options.separator();
+        match self {
+            Cell::Text(text) => text.clone(),
+            Cell::List(items) if !options.escape_lists => items.join(&options.delimiter),
+            Cell::List(items) => items
+                .iter()
+                .map(|item| {
+                    item.replace(LIST_ESCAPE, &format!("{LIST_ESCAPE}{LIST_ESCAPE}"))
+                        .replace(separator, &format!("{LIST_ESCAPE}{separator}"))
+                })
+                .collect::<Vec<_>>()
+                .join(&options.delimiter),
+            Cell::Number(number) => number.to_string(),
+        }
+    }
+}
+
+impl Serialize for Cell {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Cell::Text(text) => s.serialize_str(text),
+            Cell::List(items) => items.serialize(s),
+            Cell::Number(number) => s.serialize_i128(*number),
+        }
+    }
+}
+
+/// A word exported as an object of the JSON. The columns are kept in the order of the options
+struct JsonRow<'a> {
+    options: &'a ExportOptions,
+    key: &'a str,
+    word: &'a Word,
+}
+
+impl Serialize for JsonRow<'_> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut map = s.serialize_map(Some(self.options.columns.len()))?;
+        for column in &self.options.columns {
+            map.serialize_entry(
+                self.options.header(*column),
+                &column.value(self.key, self.word),
+            )?;
+        }
+
+        map.end()
+    }
+}
+
+/// Columns, order, list delimiter and header names used by an export
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    columns: Vec<Column>,
+    delimiter: String,
+    headers: HashMap<Column, String>,
+    escape_lists: bool,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions {
+            columns: vec![
+                Column::Key,
+                Column::Written,
+                Column::Pronunciations,
+                Column::Translations,
+                Column::Count,
+                Column::PartsOfSpeech,
+            ],
+            delimiter: DEFAULT_LIST_DELIMITER.to_string(),
+            headers: HashMap::new(),
+            escape_lists: false,
+        }
+    }
+}
+
+impl ExportOptions {
+    /// Options matching the fields of a word. The key is not exported and the lists are joined with a comma
+    pub fn word_fields() -> Self {
+        ExportOptions {
+            columns: vec![
+                Column::Written,
+                Column::Pronunciations,
+                Column::Translations,
+                Column::Count,
+                Column::PartsOfSpeech,
+            ],
+            delimiter: ",".to_string(),
+            headers: HashMap::new(),
+            escape_lists: false,
+        }
+    }
+
+    /// Set the exported columns. The columns are exported in the given order
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - &[Column]
+    pub fn with_columns(mut self, columns: &[Column]) -> Self {
+        self.columns = columns.to_vec();
+        self
+    }
+
+    /// Set the separator of the items of a list. It's not used by the JSON which keeps the lists as arrays
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - S
+    pub fn with_delimiter<S: AsRef<str>>(mut self, delimiter: S) -> Self {
+        self.delimiter = delimiter.as_ref().to_string();
+        self
+    }
+
+    /// Escape the separator in the items of the lists with a backslash e.g: "to be fond of\, keen".
+    /// It's required to import a CSV or a TSV export back as an item may contain the separator
+    ///
+    /// # Arguments
+    ///
+    /// * `escape` - bool
+    pub fn with_escaped_lists(mut self, escape: bool) -> Self {
+        self.escape_lists = escape;
+        self
+    }
+
+    /// Rename the header of a column
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Column
+    /// * `name` - S
+    pub fn with_header<S: AsRef<str>>(mut self, column: Column, name: S) -> Self {
+        self.headers.insert(column, name.as_ref().to_string());
+        self
+    }
+
+    /// Get the header of a column
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - Column
+    pub fn header(&self, column: Column) -> &str {
+        self.headers
+            .get(&column)
+            .map_or(column.name(), String::as_str)
+    }
+
+    /// Get the column matching a header
+    ///
+    /// # Arguments
+    ///
+    /// * `header` - &str
+    pub(crate) fn column(&self, header: &str) -> Option<Column> {
+        COLUMNS
+            .into_iter()
+            .find(|column| self.header(*column) == header.trim())
+    }
+
+    /// Whether the separator is escaped in the items of the lists
+    pub(crate) fn escape_lists(&self) -> bool {
+        self.escape_lists
+    }
+
+    /// Get the separator of the items of a list without the spaces around it e.g: "; " -> ";"
+    pub(crate) fn separator(&self) -> &str {
+        match self.delimiter.trim() {
+            "" => &self.delimiter,
+            trimmed => trimmed,
+        }
+    }
+}
+
+/// Export words to the given format
+///
+/// # Arguments
+///
+/// * `words` - I
+/// * `format` - Format
+/// * `options` - &ExportOptions
+pub fn export_words<'a, I>(
+    words: I,
+    format: Format,
+    options: &ExportOptions,
+) -> Result<String, DictionaryError>
+where
+    I: IntoIterator<Item = (&'a str, &'a Word)>,
+{
+    let mut buffer = Vec::new();
+    write_words(words, format, options, &mut buffer)?;
+
+    Ok(String::from_utf8(buffer)?)
+}
+
+/// Write words to a writer in the given format. Each word is written as soon as it's converted
+///
+/// # Arguments
+///
+/// * `words` - I
+/// * `format` - Format
+/// * `options` - &ExportOptions
+/// * `writer` - &mut W
+pub fn write_words<'a, I, W>(
+    words: I,
+    format: Format,
+    options: &ExportOptions,
+    writer: &mut W,
+) -> Result<(), DictionaryError>
+where
+    I: IntoIterator<Item = (&'a str, &'a Word)>,
+    W: Write,
+{
+    let headers = options.columns.iter().map(|c| options.header(*c));
+    match format {
+        Format::Json => {
+            writer.write_all(b"[").map_err(write_error)?;
+            for (idx, (key, word)) in words.into_iter().enumerate() {
+                if idx > 0 {
+                    writer.write_all(b",").map_err(write_error)?;
+                }
+
+                serde_json::to_writer(&mut *writer, &JsonRow { options, key, word })
+                    .map_err(|err| DictionaryError::Serialize(err.to_string()))?;
+            }
+            writer.write_all(b"]").map_err(write_error)?;
+        }
+        Format::Csv => {
+            let mut wrt = Writer::from_writer(&mut *writer);
+            wrt.write_record(headers)?;
+            for (key, word) in words {
+                wrt.write_record(row(options, key, word))?;
+            }
+            wrt.flush().map_err(write_error)?;
+        }
+        Format::Tsv => {
+            writer
+                .write_all(tsv_line(headers).as_bytes())
+                .map_err(write_error)?;
+            for (key, word) in words {
+                writer
+                    .write_all(tsv_line(row(options, key, word)).as_bytes())
+                    .map_err(write_error)?;
+            }
+        }
+        Format::Markdown => {
+            writer
+                .write_all(markdown_line(headers).as_bytes())
+                .map_err(write_error)?;
+            writer
+                .write_all(markdown_line(options.columns.iter().map(|_| "---")).as_bytes())
+                .map_err(write_error)?;
+            for (key, word) in words {
+                writer
+                    .write_all(markdown_line(row(options, key, word)).as_bytes())
+                    .map_err(write_error)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert an error of a writer
+///
+/// # Arguments
+///
+/// * `err` - std::io::Error
+pub(crate) fn write_error(err: std::io::Error) -> DictionaryError {
+    DictionaryError::Serialize(err.to_string())
+}
+
+/// Get the cells of a word as texts
+///
+/// # Arguments
+///
+/// * `options` - &ExportOptions
+/// * `key` - &str
+/// * `word` - &Word
+fn row(options: &ExportOptions, key: &str, word: &Word) -> Vec<String> {
+    options
+        .columns
+        .iter()
+        .map(|column| column.value(key, word).to_text(options))
+        .collect()
+}
+
+/// Create a line of a tab separated file. Tabs and new lines of the cells are replaced by spaces
+///
+/// # Arguments
+///
+/// * `cells` - I
+fn tsv_line<I: IntoIterator<Item = S>, S: AsRef<str>>(cells: I) -> String {
+    let mut line = cells
+        .into_iter()
+        .map(|cell| cell.as_ref().replace(['\t', '\n', '\r'], " "))
+        .collect::<Vec<_>>()
+        .join("\t");
+
+    line.push('\n');
+    line
+}
+
+/// Create a line of a markdown table. Pipes are escaped and new lines are replaced by a line break
+///
+/// # Arguments
+///
+/// * `cells` - I
+fn markdown_line<I: IntoIterator<Item = S>, S: AsRef<str>>(cells: I) -> String {
+    let cells = cells
+        .into_iter()
+        .map(|cell| cell.as_ref().replace('|', "\\|").replace('\n', "<br>"))
+        .collect::<Vec<_>>();
+
+    format!("| {} |\n", cells.join(" | "))
 }
 
 /// Helper method to export a vec of items which implement the Serialize trait
@@ -40,3 +496,136 @@ where
 
     s.serialize_str(&str)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word::{PartOfSpeech, WordParserResult};
+
+    fn words() -> WordParserResult {
+        WordParserResult::from([(
+            "喜歡".to_string(),
+            Word {
+                written: vec!["喜歡".to_string(), "喜欢".to_string()],
+                pronunciations: vec!["xi3".to_string(), "huan5".to_string()],
+                translations: vec!["to like".to_string(), "to be fond of, keen".to_string()],
+                count: 2,
+                parts_of_speech: vec![PartOfSpeech::Verb],
+                ..Default::default()
+            },
+        )])
+    }
+
+    #[test]
+    fn expect_to_export_json_with_arrays() {
+        let json = words().to_json().unwrap();
+
+        assert_eq!(
+            json,
+            r#"[{"key":"喜歡","written":["喜歡","喜欢"],"pronunciations":["xi3","huan5"],"translations":["to like","to be fond of, keen"],"count":2,"parts_of_speech":["v"]}]"#
+        );
+    }
+
+    #[test]
+    fn expect_to_export_selected_columns() {
+        let options = ExportOptions::default()
+            .with_columns(&[Column::Translations, Column::Key])
+            .with_delimiter(" / ")
+            .with_header(Column::Key, "word");
+
+        let tsv = words().export(Format::Tsv, &options).unwrap();
+        assert_eq!(
+            tsv,
+            "translations\tword\nto like / to be fond of, keen\t喜歡\n"
+        );
+
+        let csv = words().export(Format::Csv, &options).unwrap();
+        assert_eq!(
+            csv,
+            "translations,word\n\"to like / to be fond of, keen\",喜歡\n"
+        );
+    }
+
+    #[test]
+    fn expect_to_export_markdown_table() {
+        let options = ExportOptions::default().with_columns(&[Column::Key, Column::Count]);
+        let mut words = words();
+        words.insert(
+            "a|b".to_string(),
+            Word {
+                count: 1,
+                ..Default::default()
+            },
+        );
+
+        let markdown = words.export(Format::Markdown, &options).unwrap();
+        assert_eq!(
+            markdown,
+            "| key | count |\n| --- | --- |\n| a\\|b | 1 |\n| 喜歡 | 2 |\n"
+        );
+    }
+
+    #[test]
+    fn expect_to_stream_export_to_writer() {
+        let mut words = words();
+        words.insert(
+            "開車".to_string(),
+            Word {
+                translations: vec!["to drive".to_string()],
+                count: 1,
+                ..Default::default()
+            },
+        );
+        let options = ExportOptions::default().with_columns(&[Column::Key, Column::Translations]);
+
+        let mut json = Vec::new();
+        words.write_to(Format::Json, &options, &mut json).unwrap();
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            r#"[{"key":"喜歡","translations":["to like","to be fond of, keen"]},{"key":"開車","translations":["to drive"]}]"#
+        );
+
+        let mut csv = Vec::new();
+        words.write_to(Format::Csv, &options, &mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "key,translations\n喜歡,\"to like; to be fond of, keen\"\n開車,to drive\n"
+        );
+
+        let mut empty = Vec::new();
+        WordParserResult::new()
+            .write_to(Format::Json, &options, &mut empty)
+            .unwrap();
+        assert_eq!(String::from_utf8(empty).unwrap(), "[]");
+    }
+
+    #[test]
+    fn expect_to_keep_word_fields_in_csv() {
+        assert_eq!(
+            words().to_csv().unwrap(),
+            "written,pronunciations,translations,count,parts_of_speech\n\"喜歡,喜欢\",\"xi3,huan5\",\"to like,to be fond of, keen\",2,v\n"
+        );
+    }
+
+    #[test]
+    fn expect_to_escape_lists_when_enabled() {
+        let options = ExportOptions::word_fields()
+            .with_columns(&[Column::Translations])
+            .with_escaped_lists(true);
+
+        assert_eq!(
+            words().export(Format::Csv, &options).unwrap(),
+            "translations\n\"to like,to be fond of\\, keen\"\n"
+        );
+    }
+
+    #[test]
+    fn expect_to_export_serializable_items_to_csv() {
+        let words = words().into_values().collect::<Vec<_>>();
+
+        assert_eq!(
+            export_to_csv(words).unwrap(),
+            "written,pronunciations,translations,count,parts_of_speech\n\"喜歡,喜欢\",\"xi3,huan5\",\"to like,to be fond of, keen\",2,v\n"
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use crate::word::{Reading, Romanizer, SentenceSegmenter, TextSegment};
+
+/// Class of the words which are not present in the dictionary
+const UNKNOWN_CLASS: &str = "unknown";
+
+/// Render a sentence as HTML where each word is annotated with its reading in a `<ruby>` element.
+/// The translations of a word are set in its title so that they're displayed as a tooltip, every syllable
+/// of the reading has a CSS class for its tone and the words which are not present in the dictionary
+/// have the `unknown` class.
+pub trait RubyRenderer: SentenceSegmenter + Romanizer {
+    /// Render a sentence as HTML with ruby annotations. The words are rendered in the order of the sentence
+    ///
+    /// # Arguments
+    ///
+    /// * `sentence` - S
+    fn to_ruby_html<S: AsRef<str>>(&self, sentence: S) -> String {
+        self.segment_sentence(sentence)
+            .iter()
+            .map(|segment| render_segment(segment, &self.readings(segment)))
+            .collect()
+    }
+}
+
+impl<T: SentenceSegmenter + Romanizer> RubyRenderer for T {}
+
+/// Render a segment. Each character gets its own annotation when there is one reading per character,
+/// otherwise the readings are written together above the segment
+///
+/// # Arguments
+///
+/// * `segment` - &TextSegment
+/// * `readings` - &[Reading]
+fn render_segment(segment: &TextSegment, readings: &[Reading]) -> String {
+    let text = escape_html(&segment.text);
+    if segment.punctuation || (segment.word.is_none() && readings.is_empty()) {
+        return match segment.is_unknown() {
+            true => format!("<span class=\"{UNKNOWN_CLASS}\">{text}</span>"),
+            false => text,
+        };
+    }
+
+    let mut html = match segment.is_unknown() {
+        true => format!("<ruby class=\"word {UNKNOWN_CLASS}\""),
+        false => String::from("<ruby class=\"word\""),
+    };
+
+    if let Some(word) = segment.word.as_ref().filter(|w| !w.translations.is_empty()) {
+        html.push_str(&format!(
+            " title=\"{}\"",
+            escape_html(&word.translations.join("; "))
+        ));
+    }
+    html.push('>');
+
+    let chars = segment.text.chars().collect::<Vec<_>>();
+    if chars.len() == readings.len() {
+        for (c, reading) in chars.iter().zip(readings) {
+            let c = escape_html(&c.to_string());
+            match &reading.tone {
+                Some(tone) => html.push_str(&format!("<span class=\"{tone}\">{c}</span>")),
+                None => html.push_str(&c),
+            }
+            html.push_str(&format!(
+                "<rt{}>{}</rt>",
+                class_attribute(reading),
+                escape_html(&reading.text)
+            ));
+        }
+    } else {
+        html.push_str(&text);
+        html.push_str("<rt>");
+        for reading in readings {
+            html.push_str(&format!(
+                "<span{}>{}</span>",
+                class_attribute(reading),
+                escape_html(&reading.text)
+            ));
+        }
+        html.push_str("</rt>");
+    }
+
+    html.push_str("</ruby>");
+    html
+}
+
+/// Get the class attribute of the tone of a reading
+///
+/// # Arguments
+///
+/// * `reading` - &Reading
+fn class_attribute(reading: &Reading) -> String {
+    reading
+        .tone
+        .as_ref()
+        .map(|tone| format!(" class=\"{tone}\""))
+        .unwrap_or_default()
+}
+
+/// Escape the characters which have a meaning in HTML text and attributes
+///
+/// # Arguments
+///
+/// * `value` - &str
+pub fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word::Word;
+
+    fn segment(text: &str, translations: Option<&[&str]>) -> TextSegment {
+        TextSegment {
+            text: text.to_string(),
+            word: translations.map(|translations| Word {
+                translations: translations.iter().map(|t| t.to_string()).collect(),
+                ..Default::default()
+            }),
+            punctuation: false,
+        }
+    }
+
+    fn reading(text: &str, tone: &str) -> Reading {
+        Reading {
+            text: text.to_string(),
+            tone: Some(tone.to_string()),
+        }
+    }
+
+    #[test]
+    fn expect_to_render_reading_per_character() {
+        let html = render_segment(
+            &segment("喜歡", Some(&["to like", "to be \"fond\" of"])),
+            &[reading("xǐ", "tone3"), reading("huan", "tone5")],
+        );
+
+        assert_eq!(
+            html,
+            "<ruby class=\"word\" title=\"to like; to be &quot;fond&quot; of\"><span class=\"tone3\">喜</span><rt class=\"tone3\">xǐ</rt><span class=\"tone5\">歡</span><rt class=\"tone5\">huan</rt></ruby>"
+        );
+    }
+
+    #[test]
+    fn expect_to_render_unknown_words_and_escape_input() {
+        assert_eq!(
+            render_segment(&segment("<b>", None), &[]),
+            "<span class=\"unknown\">&lt;b&gt;</span>"
+        );
+        assert_eq!(render_segment(&segment("42 ", None), &[]), "42 ");
+        assert_eq!(
+            render_segment(&segment("ຟຼິບ", None), &[reading("flip", "tone-high")]),
+            "<ruby class=\"word unknown\">ຟຼິບ<rt><span class=\"tone-high\">flip</span></rt></ruby>"
+        );
+    }
+
+    #[cfg(feature = "chinese")]
+    #[test]
+    fn expect_to_render_chinese_sentence() {
+        use crate::KeyVariant;
+        use crate::dictionary::{Chinese, Dictionary, Initializer, Lang};
+        use std::path::PathBuf;
+
+        let mut dictionary =
+            Dictionary::<Chinese>::initialize(Lang::Chinese(KeyVariant::Traditional)).unwrap();
+        dictionary.load(PathBuf::from("./cedict_ts.u8")).unwrap();
+
+        let html = dictionary.to_ruby_html("你好, X");
+        assert!(html.starts_with("<ruby class=\"word\" title=\""));
+        assert!(html.contains("<span class=\"tone3\">你</span><rt class=\"tone3\">nǐ</rt>"));
+        assert!(html.ends_with("</ruby>, <span class=\"unknown\">X</span>"));
+    }
+
+    #[cfg(feature = "laotian")]
+    #[test]
+    fn expect_to_render_laotian_sentence() {
+        use crate::dictionary::{Dictionary, Initializer, Lang, Laotian};
+        use std::path::PathBuf;
+
+        let mut dictionary = Dictionary::<Laotian>::initialize(Lang::Laotian(None)).unwrap();
+        dictionary
+            .load(PathBuf::from("./lao-eng-dictionary.csv"))
+            .unwrap();
+
+        let html = dictionary.to_ruby_html("ຮັກ.");
+        assert_eq!(
+            html,
+            "<ruby class=\"word\" title=\"love\">ຮັກ<rt><span class=\"tone-mid\">hak</span></rt></ruby>."
+        );
+    }
+}
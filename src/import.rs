@@ -0,0 +1,440 @@
+use crate::error::DictionaryError;
+use crate::export::{Column, ExportOptions, Format, LIST_ESCAPE};
+use crate::word::{PartOfSpeech, Word, WordParserResult};
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Read};
+
+/// Load words which have been exported by the `Export` trait. The same options as the export need
+/// to be used so that the headers and the lists can be read back. A CSV or a TSV can only be imported
+/// when its lists have been escaped with [`ExportOptions::with_escaped_lists`]. Every row needs a key
+/// or a written form, otherwise an error is returned.
+pub trait Import: Sized {
+    /// Import words from a reader in the given format
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - R
+    /// * `format` - Format
+    /// * `options` - &ExportOptions
+    fn import<R: Read>(
+        reader: R,
+        format: Format,
+        options: &ExportOptions,
+    ) -> Result<Self, DictionaryError>;
+
+    /// Import words from a JSON created with the default options
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - R
+    fn from_json<R: Read>(reader: R) -> Result<Self, DictionaryError> {
+        Self::import(reader, Format::Json, &ExportOptions::default())
+    }
+}
+
+impl Import for WordParserResult {
+    fn import<R: Read>(
+        reader: R,
+        format: Format,
+        options: &ExportOptions,
+    ) -> Result<Self, DictionaryError> {
+        let mut words = WordParserResult::new();
+        let mut insert = |row: usize, key: Option<String>, word: Word| {
+            // the first written form is used when the key hasn't been exported
+            let Some(key) = key.or_else(|| word.written.first().cloned()) else {
+                return Err(DictionaryError::Serialize(format!(
+                    "the row {row} has neither a key nor a written form"
+                )));
+            };
+
+            words.insert(key, word);
+            Ok(())
+        };
+
+        // an item of an unescaped list can't be told apart from two items
+        if matches!(format, Format::Csv | Format::Tsv) && !options.escape_lists() {
+            return Err(DictionaryError::Serialize(
+                "the lists of a csv or a tsv need to be escaped to be imported".to_string(),
+            ));
+        }
+
+        match format {
+            Format::Json => {
+                let rows: Vec<serde_json::Map<String, Value>> = serde_json::from_reader(reader)
+                    .map_err(|err| DictionaryError::Serialize(err.to_string()))?;
+
+                for (idx, row) in rows.iter().enumerate() {
+                    let (key, word) = read_row(
+                        options,
+                        row.iter()
+                            .filter_map(|(header, value)| Some((options.column(header)?, value))),
+                    )?;
+                    insert(idx + 1, key, word)?;
+                }
+            }
+            Format::Csv => {
+                let mut csv = csv::Reader::from_reader(reader);
+                let columns = csv
+                    .headers()?
+                    .iter()
+                    .map(|header| options.column(header))
+                    .collect::<Vec<_>>();
+
+                for (idx, record) in csv.records().enumerate() {
+                    let record = record?;
+                    let (key, word) = read_text_row(options, &columns, record.iter())?;
+                    insert(idx + 1, key, word)?;
+                }
+            }
+            Format::Tsv => {
+                let mut lines = BufReader::new(reader).lines();
+                let columns = match lines.next() {
+                    Some(header) => header
+                        .map_err(|err| DictionaryError::Serialize(err.to_string()))?
+                        .split('\t')
+                        .map(|header| options.column(header))
+                        .collect::<Vec<_>>(),
+                    None => Vec::new(),
+                };
+
+                for (idx, line) in lines.enumerate() {
+                    let line = line.map_err(|err| DictionaryError::Serialize(err.to_string()))?;
+                    if line.is_empty() {
+                        continue;
+                    }
+
+                    let (key, word) = read_text_row(options, &columns, line.split('\t'))?;
+                    insert(idx + 1, key, word)?;
+                }
+            }
+            Format::Markdown => {
+                return Err(DictionaryError::Serialize(
+                    "markdown tables can't be imported".to_string(),
+                ));
+            }
+        }
+
+        Ok(words)
+    }
+}
+
+/// Read a row of a CSV or a TSV. Cells which don't match a column are ignored
+///
+/// # Arguments
+///
+/// * `options` - &ExportOptions
+/// * `columns` - &[Option<Column>]
+/// * `cells` - I
+fn read_text_row<'a, I>(
+    options: &ExportOptions,
+    columns: &[Option<Column>],
+    cells: I,
+) -> Result<(Option<String>, Word), DictionaryError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let cells = cells
+        .zip(columns)
+        .filter_map(|(cell, column)| Some(((*column)?, Value::String(cell.to_string()))))
+        .collect::<Vec<_>>();
+
+    read_row(
+        options,
+        cells.iter().map(|(column, value)| (*column, value)),
+    )
+}
+
+/// Create a word from the values of its columns. Lists can be either an array or a text joined
+/// with the delimiter of the options. `Word` doesn't derive `Deserialize` as its serialized form
+/// joins the lists with a comma without escaping them and the columns of an export are configurable,
+/// hence the rows are read column by column with the options of the export
+///
+/// # Arguments
+///
+/// * `options` - &ExportOptions
+/// * `cells` - I
+fn read_row<'a, I>(
+    options: &ExportOptions,
+    cells: I,
+) -> Result<(Option<String>, Word), DictionaryError>
+where
+    I: Iterator<Item = (Column, &'a Value)>,
+{
+    let mut key = None;
+    let mut word = Word::default();
+
+    for (column, value) in cells {
+        match column {
+            Column::Key => key = value.as_str().map(String::from),
+            Column::Written => word.written = read_list(options, value),
+            Column::Pronunciations => word.pronunciations = read_list(options, value),
+            Column::Translations => word.translations = read_list(options, value),
+            Column::Count => word.count = read_count(value)?,
+            Column::PartsOfSpeech => {
+                word.parts_of_speech = read_list(options, value)
+                    .iter()
+                    .map(|pos| PartOfSpeech::from(pos.as_str()))
+                    .collect()
+            }
+            Column::Levels => {
+                word.levels = read_list(options, value)
+                    .iter()
+                    .map(|level| level.parse())
+                    .collect::<Result<_, _>>()?
+            }
+        }
+    }
+
+    Ok((key, word))
+}
+
+/// Read a list from an array or from a text joined with the delimiter. The separators which are
+/// escaped belong to the items
+///
+/// # Arguments
+///
+/// * `options` - &ExportOptions
+/// * `value` - &Value
+fn read_list(options: &ExportOptions, value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| item.as_str().map(String::from))
+            .collect(),
+        Value::String(text) if text.is_empty() => Vec::new(),
+        // the separator doesn't contain the spaces of the delimiter as they may have been removed
+        Value::String(text) => split_list(text, options.separator()),
+        _ => Vec::new(),
+    }
+}
+
+/// Split a text on the separators which are not escaped e.g: "to like, to be fond of\, keen"
+///
+/// # Arguments
+///
+/// * `text` - &str
+/// * `separator` - &str
+fn split_list(text: &str, separator: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut item = String::new();
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        if let Some(next) = rest.strip_prefix(separator) {
+            items.push(item.trim().to_string());
+            item.clear();
+            rest = next;
+            continue;
+        }
+
+        let mut chars = rest.chars();
+        let Some(c) = chars.next() else {
+            break;
+        };
+        rest = chars.as_str();
+
+        // an escaped separator or escape character belongs to the item
+        if c == LIST_ESCAPE {
+            if let Some(next) = rest.strip_prefix(separator) {
+                item.push_str(separator);
+                rest = next;
+                continue;
+            }
+
+            if let Some(next) = rest.strip_prefix(LIST_ESCAPE) {
+                item.push(LIST_ESCAPE);
+                rest = next;
+                continue;
+            }
+        }
+
+        item.push(c);
+    }
+
+    items.push(item.trim().to_string());
+    items
+}
+
+/// Read the count of a word from a number or a text
+///
+/// # Arguments
+///
+/// * `value` - &Value
+fn read_count(value: &Value) -> Result<i128, DictionaryError> {
+    let count = match value {
+        Value::Number(number) => number.as_i64().map(i128::from),
+        Value::String(text) => text.trim().parse().ok(),
+        _ => None,
+    };
+
+    count.ok_or_else(|| DictionaryError::Serialize(format!("invalid count {value}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::Export;
+    use crate::word::{Level, LevelSystem};
+
+    fn words() -> WordParserResult {
+        WordParserResult::from([
+            (
+                "喜欢".to_string(),
+                Word {
+                    written: vec!["喜歡".to_string(), "喜欢".to_string()],
+                    pronunciations: vec!["xi3".to_string(), "huan5".to_string()],
+                    translations: vec![
+                        "to like".to_string(),
+                        "to be fond of, keen".to_string(),
+                        "variant of 了; also".to_string(),
+                    ],
+                    count: 2,
+                    parts_of_speech: vec![PartOfSpeech::Verb],
+                    levels: vec![Level {
+                        system: LevelSystem::Hsk2,
+                        level: 1,
+                    }],
+                    ..Default::default()
+                },
+            ),
+            (
+                "ໄປ".to_string(),
+                Word {
+                    written: vec!["ໄປ".to_string()],
+                    translations: vec!["to go".to_string()],
+                    count: 1,
+                    ..Default::default()
+                },
+            ),
+        ])
+    }
+
+    fn assert_same_words(imported: &WordParserResult) {
+        let expected = words();
+        assert_eq!(imported.len(), expected.len());
+        assert_eq!(
+            imported.keys().collect::<Vec<_>>(),
+            expected.keys().collect::<Vec<_>>()
+        );
+
+        for (key, word) in expected {
+            let res = imported.get(&key).unwrap();
+            assert_eq!(res.written, word.written);
+            assert_eq!(res.pronunciations, word.pronunciations);
+            assert_eq!(res.translations, word.translations);
+            assert_eq!(res.count, word.count);
+            assert_eq!(res.parts_of_speech, word.parts_of_speech);
+            assert_eq!(res.levels, word.levels);
+        }
+    }
+
+    #[test]
+    fn expect_to_import_json_export() {
+        let options = ExportOptions::default().with_columns(&[
+            Column::Key,
+            Column::Written,
+            Column::Pronunciations,
+            Column::Translations,
+            Column::Count,
+            Column::PartsOfSpeech,
+            Column::Levels,
+        ]);
+
+        let json = words().export(Format::Json, &options).unwrap();
+        let imported = WordParserResult::import(json.as_bytes(), Format::Json, &options).unwrap();
+
+        assert_same_words(&imported);
+    }
+
+    #[test]
+    fn expect_to_import_csv_and_tsv_exports() {
+        let options = ExportOptions::default()
+            .with_columns(&[
+                Column::Levels,
+                Column::Key,
+                Column::Written,
+                Column::Pronunciations,
+                Column::Translations,
+                Column::Count,
+                Column::PartsOfSpeech,
+            ])
+            .with_delimiter(" | ")
+            .with_header(Column::Key, "word")
+            .with_escaped_lists(true);
+
+        for format in [Format::Csv, Format::Tsv] {
+            let export = words().export(format, &options).unwrap();
+            let imported = WordParserResult::import(export.as_bytes(), format, &options).unwrap();
+
+            assert_same_words(&imported);
+        }
+    }
+
+    #[test]
+    fn expect_to_import_escaped_tsv_export() {
+        let options = ExportOptions::default().with_escaped_lists(true);
+        let tsv = words().export(Format::Tsv, &options).unwrap();
+        assert!(tsv.contains("to like; to be fond of, keen; variant of 了\\; also"));
+
+        let imported = WordParserResult::import(tsv.as_bytes(), Format::Tsv, &options).unwrap();
+
+        assert_eq!(imported.len(), 2);
+        assert_eq!(
+            imported.get("喜欢").unwrap().translations,
+            words().get("喜欢").unwrap().translations
+        );
+        assert_eq!(imported.get("ໄປ").unwrap().translations, vec!["to go"]);
+    }
+
+    #[test]
+    fn expect_to_use_written_form_without_key() {
+        let options = ExportOptions::word_fields().with_escaped_lists(true);
+        let csv = words().export(Format::Csv, &options).unwrap();
+        let imported = WordParserResult::import(csv.as_bytes(), Format::Csv, &options).unwrap();
+
+        assert_eq!(imported.len(), 2);
+        let liked = imported.get("喜歡").unwrap();
+        assert_eq!(liked.written, vec!["喜歡", "喜欢"]);
+        assert_eq!(
+            liked.translations,
+            vec!["to like", "to be fond of, keen", "variant of 了; also"]
+        );
+        assert_eq!(liked.count, 2);
+        assert_eq!(imported.get("ໄປ").unwrap().translations, vec!["to go"]);
+
+        assert!(
+            WordParserResult::import("".as_bytes(), Format::Markdown, &ExportOptions::default())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn expect_to_report_rows_without_key_and_written_form() {
+        let csv = "written,translations\n,to go\n";
+        let options = ExportOptions::word_fields().with_escaped_lists(true);
+        let res = WordParserResult::import(csv.as_bytes(), Format::Csv, &options);
+
+        assert!(matches!(
+            res,
+            Err(DictionaryError::Serialize(message)) if message.contains("row 1")
+        ));
+    }
+
+    #[test]
+    fn expect_to_require_escaped_lists() {
+        let csv = words().to_csv().unwrap();
+        let res =
+            WordParserResult::import(csv.as_bytes(), Format::Csv, &ExportOptions::word_fields());
+
+        assert!(matches!(res, Err(DictionaryError::Serialize(_))));
+    }
+
+    #[test]
+    fn expect_to_split_list_on_separators_which_are_not_escaped() {
+        assert_eq!(
+            split_list("a, b\\, c, d\\\\, e", ","),
+            vec!["a", "b, c", "d\\", "e"]
+        );
+        assert_eq!(split_list("a; b", ";"), vec!["a", "b"]);
+    }
+}
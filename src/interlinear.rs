@@ -0,0 +1,405 @@
+use crate::word::{Romanizer, SentenceSegmenter, TextSegment, Word};
+use std::collections::HashMap;
+
+/// Gloss of the words which are not present in the dictionary
+const UNKNOWN_GLOSS: &str = "?";
+
+/// Prefix of the cedict translations which only give the classifier of a noun e.g: CL:個|个[ge4]
+const CLASSIFIER_PREFIX: &str = "CL:";
+
+/// Sense of a word used as its gloss
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GlossChoice {
+    /// The first translation of the dictionary
+    #[default]
+    First,
+    /// The translation with the fewest characters
+    Shortest,
+}
+
+/// LaTeX package used to typeset the glosses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LatexPackage {
+    Gb4e,
+    Expex,
+}
+
+/// A word of a sentence with its romanization and its gloss
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlossedWord {
+    /// Text of the word as written in the sentence. The punctuations which follow the word are kept with it
+    pub text: String,
+    pub romanization: String,
+    pub gloss: String,
+}
+
+/// Render a sentence as a Leipzig style interlinear glossed text. The first line contains the words of
+/// the sentence, the second line their romanization and the third line a short english gloss of each word.
+/// The words of a gloss are joined with a dot e.g: "to like" -> "to.like"
+#[derive(Debug, Clone, Default)]
+pub struct InterlinearGlosser {
+    choice: GlossChoice,
+    overrides: HashMap<String, String>,
+}
+
+impl InterlinearGlosser {
+    /// Create a glosser which uses the first translation of the words
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the sense used as the gloss of the words
+    ///
+    /// # Arguments
+    ///
+    /// * `choice` - GlossChoice
+    pub fn with_choice(mut self, choice: GlossChoice) -> Self {
+        self.choice = choice;
+        self
+    }
+
+    /// Override the gloss of a word. The word is matched with its text in the sentence or one of its written forms
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - S
+    /// * `gloss` - G
+    pub fn with_gloss<S: AsRef<str>, G: AsRef<str>>(mut self, word: S, gloss: G) -> Self {
+        self.overrides
+            .insert(word.as_ref().to_string(), gloss.as_ref().to_string());
+        self
+    }
+
+    /// Gloss the words of a sentence in the order of the sentence. Spaces are removed and the punctuations
+    /// are attached to the previous word
+    ///
+    /// # Arguments
+    ///
+    /// * `dictionary` - &D
+    /// * `sentence` - S
+    pub fn gloss<D: SentenceSegmenter + Romanizer, S: AsRef<str>>(
+        &self,
+        dictionary: &D,
+        sentence: S,
+    ) -> Vec<GlossedWord> {
+        let mut words: Vec<GlossedWord> = Vec::new();
+
+        for segment in dictionary.segment_sentence(sentence) {
+            let text = segment.text.trim();
+            if text.is_empty() {
+                continue;
+            }
+
+            if segment.punctuation {
+                match words.last_mut() {
+                    Some(previous) => {
+                        previous.text.push_str(text);
+                        previous.romanization.push_str(text);
+                    }
+                    None => words.push(GlossedWord {
+                        text: text.to_string(),
+                        romanization: text.to_string(),
+                        gloss: String::new(),
+                    }),
+                }
+                continue;
+            }
+
+            let romanization = dictionary
+                .readings(&segment)
+                .iter()
+                .map(|reading| reading.text.as_str())
+                .collect::<String>();
+
+            words.push(GlossedWord {
+                text: text.to_string(),
+                romanization: match romanization.trim() {
+                    "" => text.to_string(),
+                    romanization => romanization.to_string(),
+                },
+                gloss: self.gloss_segment(&segment),
+            });
+        }
+
+        words
+    }
+
+    /// Render the glosses of a sentence as plain text. The words are aligned in columns
+    ///
+    /// # Arguments
+    ///
+    /// * `dictionary` - &D
+    /// * `sentence` - S
+    pub fn to_text<D: SentenceSegmenter + Romanizer, S: AsRef<str>>(
+        &self,
+        dictionary: &D,
+        sentence: S,
+    ) -> String {
+        let words = self.gloss(dictionary, sentence);
+        let widths = words
+            .iter()
+            .map(|word| {
+                [&word.text, &word.romanization, &word.gloss]
+                    .iter()
+                    .map(|cell| display_width(cell))
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+
+        let line = |cell: fn(&GlossedWord) -> &str| {
+            words
+                .iter()
+                .zip(&widths)
+                .map(|(word, width)| {
+                    let cell = cell(word);
+                    format!("{cell}{}", " ".repeat(width - display_width(cell)))
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+                .trim_end()
+                .to_string()
+        };
+
+        format!(
+            "{}\n{}\n{}\n",
+            line(|word| &word.text),
+            line(|word| &word.romanization),
+            line(|word| &word.gloss)
+        )
+    }
+
+    /// Render the glosses of a sentence as a LaTeX example for the gb4e or the expex package
+    ///
+    /// # Arguments
+    ///
+    /// * `dictionary` - &D
+    /// * `sentence` - S
+    /// * `package` - LatexPackage
+    pub fn to_latex<D: SentenceSegmenter + Romanizer, S: AsRef<str>>(
+        &self,
+        dictionary: &D,
+        sentence: S,
+        package: LatexPackage,
+    ) -> String {
+        let words = self.gloss(dictionary, sentence);
+        let line = |cell: fn(&GlossedWord) -> &str| {
+            words
+                .iter()
+                .map(|word| latex_cell(cell(word)))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        let (text, romanization, gloss) = (
+            line(|word| &word.text),
+            line(|word| &word.romanization),
+            line(|word| &word.gloss),
+        );
+
+        match package {
+            LatexPackage::Gb4e => format!(
+                "\\begin{{exe}}\n\\ex\n\\glll {text}\\\\\n{romanization}\\\\\n{gloss}\\\\\n\\end{{exe}}\n"
+            ),
+            LatexPackage::Expex => format!(
+                "\\ex\n\\begingl\n\\gla {text}//\n\\glb {romanization}//\n\\glc {gloss}//\n\\endgl\n\\xe\n"
+            ),
+        }
+    }
+
+    /// Get the gloss of a segment. The overrides are used first, then the sense of the dictionary
+    ///
+    /// # Arguments
+    ///
+    /// * `segment` - &TextSegment
+    fn gloss_segment(&self, segment: &TextSegment) -> String {
+        let overridden = std::iter::once(&segment.text)
+            .chain(segment.word.iter().flat_map(|word| &word.written))
+            .find_map(|written| self.overrides.get(written));
+
+        if let Some(gloss) = overridden {
+            return gloss.clone();
+        }
+
+        segment
+            .word
+            .as_ref()
+            .and_then(|word| self.sense(word))
+            .map(|sense| sense.split_whitespace().collect::<Vec<_>>().join("."))
+            .unwrap_or_else(|| UNKNOWN_GLOSS.to_string())
+    }
+
+    /// Choose the translation of a word used as its gloss. The translations which only give the classifier
+    /// of a noun are ignored
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &'a Word
+    fn sense<'a>(&self, word: &'a Word) -> Option<&'a str> {
+        let mut senses = word
+            .translations
+            .iter()
+            .map(|translation| translation.trim())
+            .filter(|translation| {
+                !translation.is_empty() && !translation.starts_with(CLASSIFIER_PREFIX)
+            });
+
+        match self.choice {
+            GlossChoice::First => senses.next(),
+            GlossChoice::Shortest => senses.min_by_key(|translation| translation.chars().count()),
+        }
+    }
+}
+
+/// Get the width of a text in a monospaced font. CJK characters take two columns and the combining marks
+/// (e.g: the vowels & the tone marks of laotian) don't take any
+///
+/// # Arguments
+///
+/// * `text` - &str
+fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c as u32 {
+            0x0300..=0x036F | 0x0EB1 | 0x0EB4..=0x0EBC | 0x0EC8..=0x0ECD => 0,
+            0x1100..=0x115F
+            | 0x2E80..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFE30..=0xFE4F
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6
+            | 0x20000..=0x3FFFD => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+/// Escape a cell of a LaTeX gloss. Cells which are empty or contain spaces are grouped with braces
+///
+/// # Arguments
+///
+/// * `value` - &str
+fn latex_cell(value: &str) -> String {
+    let escaped = value
+        .chars()
+        .map(|c| match c {
+            '\\' => "\\textbackslash{}".to_string(),
+            '~' => "\\textasciitilde{}".to_string(),
+            '^' => "\\textasciicircum{}".to_string(),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => format!("\\{c}"),
+            _ => c.to_string(),
+        })
+        .collect::<String>();
+
+    if escaped.is_empty() || escaped.contains(char::is_whitespace) {
+        return format!("{{{escaped}}}");
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(translations: &[&str]) -> Word {
+        Word {
+            written: vec!["喜歡".to_string(), "喜欢".to_string()],
+            translations: translations.iter().map(|t| t.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn expect_to_choose_sense() {
+        let glosser = InterlinearGlosser::new();
+        let word = word(&["CL:個|个[ge4]", "to be fond of", "to like"]);
+        assert_eq!(glosser.sense(&word), Some("to be fond of"));
+
+        let glosser = glosser.with_choice(GlossChoice::Shortest);
+        assert_eq!(glosser.sense(&word), Some("to like"));
+    }
+
+    #[test]
+    fn expect_to_override_gloss() {
+        let segment = TextSegment {
+            text: "喜歡".to_string(),
+            word: Some(word(&["to like"])),
+            punctuation: false,
+        };
+
+        assert_eq!(InterlinearGlosser::new().gloss_segment(&segment), "to.like");
+        assert_eq!(
+            InterlinearGlosser::new()
+                .with_gloss("喜欢", "like")
+                .gloss_segment(&segment),
+            "like"
+        );
+        assert_eq!(
+            InterlinearGlosser::new().gloss_segment(&TextSegment::default()),
+            "?"
+        );
+    }
+
+    #[test]
+    fn expect_to_escape_latex_cell() {
+        assert_eq!(latex_cell("50%"), "50\\%");
+        assert_eq!(latex_cell(""), "{}");
+        assert_eq!(latex_cell("a b"), "{a b}");
+        assert_eq!(display_width("你好,"), 5);
+        assert_eq!(display_width("ກິນ"), 2);
+    }
+
+    #[cfg(feature = "chinese")]
+    #[test]
+    fn expect_to_render_chinese_glosses() {
+        use crate::KeyVariant;
+        use crate::dictionary::{Chinese, Dictionary, Initializer, Lang};
+        use std::path::PathBuf;
+
+        let mut dictionary =
+            Dictionary::<Chinese>::initialize(Lang::Chinese(KeyVariant::Traditional)).unwrap();
+        dictionary.load(PathBuf::from("./cedict_ts.u8")).unwrap();
+
+        let glosser = InterlinearGlosser::new()
+            .with_gloss("你", "2SG")
+            .with_gloss("嗎", "Q");
+        let text = glosser.to_text(&dictionary, "你喜歡開車嗎?");
+
+        assert_eq!(
+            text,
+            [
+                "你  喜歡    開車           嗎?",
+                "nǐ  xǐhuan  kāichē         ma?",
+                "2SG to.like to.drive.a.car Q",
+                "",
+            ]
+            .join("\n")
+        );
+
+        let latex = glosser.to_latex(&dictionary, "你喜歡開車嗎?", LatexPackage::Gb4e);
+        assert!(latex.starts_with("\\begin{exe}\n\\ex\n\\glll 你 喜歡 開車 嗎?\\\\\n"));
+
+        let latex = glosser.to_latex(&dictionary, "你喜歡", LatexPackage::Expex);
+        assert!(latex.contains("\\glb nǐ xǐhuan//\n"));
+    }
+
+    #[cfg(feature = "laotian")]
+    #[test]
+    fn expect_to_render_laotian_glosses() {
+        use crate::dictionary::{Dictionary, Initializer, Lang, Laotian};
+        use std::path::PathBuf;
+
+        let mut dictionary = Dictionary::<Laotian>::initialize(Lang::Laotian(None)).unwrap();
+        dictionary
+            .load(PathBuf::from("./lao-eng-dictionary.csv"))
+            .unwrap();
+
+        let words = InterlinearGlosser::new().gloss(&dictionary, "ລູກຫລ້າ ຮັກ.");
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].romanization, "loukla");
+        assert_eq!(words[0].gloss, "youngest.child");
+        assert_eq!(words[1].text, "ຮັກ.");
+        assert_eq!(words[1].gloss, "love");
+    }
+}
@@ -0,0 +1,308 @@
+use crate::dictionary::Language;
+use crate::error::DictionaryError;
+use crate::export::{ExportOptions, Format};
+use crate::import::Import;
+use crate::word::{Word, WordParserResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Read;
+
+/// Vocabulary known by a learner for each language. Words are stored with the same keys as the
+/// dictionaries, lao words are stored in their canonical spelling. It can be saved to JSON to be kept
+/// between sessions
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownWords {
+    words: BTreeMap<Language, BTreeSet<String>>,
+}
+
+/// Words of a parse result which aren't known by the learner
+#[derive(Debug, Clone)]
+pub struct VocabularyDiff {
+    pub new_words: WordParserResult,
+    /// Number of tokens of the text which are known
+    pub known_tokens: i128,
+    pub total_tokens: i128,
+    /// Percent of the tokens of the text which are known
+    pub coverage: f64,
+}
+
+impl KnownWords {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the store from its JSON representation
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - &str
+    pub fn from_json(json: &str) -> Result<Self, DictionaryError> {
+        serde_json::from_str(json).map_err(|err| DictionaryError::Serialize(err.to_string()))
+    }
+
+    /// Save the store to JSON
+    pub fn to_json(&self) -> Result<String, DictionaryError> {
+        serde_json::to_string(self).map_err(|err| DictionaryError::Serialize(err.to_string()))
+    }
+
+    /// Mark a word as known. Returns false when the word was already known
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - Language
+    /// * `word` - S
+    pub fn add<S: AsRef<str>>(&mut self, language: Language, word: S) -> bool {
+        self.words
+            .entry(language)
+            .or_default()
+            .insert(normalize(language, word.as_ref()))
+    }
+
+    /// Remove a word from the known words. Returns false when the word was not known
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - Language
+    /// * `word` - S
+    pub fn remove<S: AsRef<str>>(&mut self, language: Language, word: S) -> bool {
+        self.words
+            .get_mut(&language)
+            .is_some_and(|words| words.remove(&normalize(language, word.as_ref())))
+    }
+
+    /// Check whether a word is known
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - Language
+    /// * `word` - S
+    pub fn contains<S: AsRef<str>>(&self, language: Language, word: S) -> bool {
+        self.words
+            .get(&language)
+            .is_some_and(|words| words.contains(&normalize(language, word.as_ref())))
+    }
+
+    /// Get the known words of a language
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - Language
+    pub fn words(&self, language: Language) -> impl Iterator<Item = &str> {
+        self.words
+            .get(&language)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+
+    /// Import the words of a CSV created by the `Export` trait. The CSV is read with [`Import`] so the
+    /// same options as the export need to be used, the key and the written forms of each word are added.
+    /// Returns the number of words which have been added
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - Language
+    /// * `reader` - R
+    /// * `options` - &ExportOptions
+    pub fn import_csv<R: Read>(
+        &mut self,
+        language: Language,
+        reader: R,
+        options: &ExportOptions,
+    ) -> Result<usize, DictionaryError> {
+        let words = WordParserResult::import(reader, Format::Csv, options)?;
+        let mut added = 0;
+
+        for (key, word) in &words {
+            for form in std::iter::once(key).chain(&word.written) {
+                if !form.is_empty() && self.add(language, form) {
+                    added += 1;
+                }
+            }
+        }
+
+        Ok(added)
+    }
+
+    /// Check whether a word of a parse result is known. Every written form of the word is checked
+    /// so that a word known in traditional chinese is known in simplified chinese too
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - Language
+    /// * `key` - &str
+    /// * `word` - &Word
+    pub fn is_known(&self, language: Language, key: &str, word: &Word) -> bool {
+        self.contains(language, key) || word.written.iter().any(|w| self.contains(language, w))
+    }
+
+    /// Keep the words of a parse result which are not known and compute the share of the tokens
+    /// of the text which are known
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - Language
+    /// * `words` - &WordParserResult
+    pub fn diff(&self, language: Language, words: &WordParserResult) -> VocabularyDiff {
+        let mut new_words = WordParserResult::new();
+        let (mut known_tokens, mut total_tokens) = (0, 0);
+
+        for (key, word) in words {
+            total_tokens += word.count;
+            if self.is_known(language, key, word) {
+                known_tokens += word.count;
+            } else {
+                new_words.insert(key.clone(), word.clone());
+            }
+        }
+
+        let coverage = if total_tokens == 0 {
+            0.0
+        } else {
+            known_tokens as f64 * 100.0 / total_tokens as f64
+        };
+
+        VocabularyDiff {
+            new_words,
+            known_tokens,
+            total_tokens,
+            coverage,
+        }
+    }
+}
+
+/// Get the spelling of a word used as the key of the store. Lao words are written in their canonical
+/// spelling e.g: ລູກຫຼ້າ -> ລູກຫລ້າ
+///
+/// # Arguments
+///
+/// * `language` - Language
+/// * `word` - &str
+fn normalize(language: Language, word: &str) -> String {
+    match language {
+        #[cfg(feature = "laotian")]
+        Language::Laotian => crate::laotian::normalize::normalize(word),
+        _ => word.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(written: &[&str], count: i128) -> Word {
+        Word {
+            written: written.iter().map(|w| w.to_string()).collect(),
+            count,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn expect_to_add_and_remove_words() {
+        let mut known = KnownWords::new();
+        assert!(known.add(Language::Chinese, "你好"));
+        assert!(!known.add(Language::Chinese, "你好"));
+        assert!(known.add(Language::Laotian, "ໄປ"));
+
+        assert!(known.contains(Language::Chinese, "你好"));
+        assert!(!known.contains(Language::Laotian, "你好"));
+
+        assert!(known.remove(Language::Chinese, "你好"));
+        assert!(!known.remove(Language::Chinese, "你好"));
+        assert_eq!(
+            known.words(Language::Laotian).collect::<Vec<_>>(),
+            vec!["ໄປ"]
+        );
+    }
+
+    #[cfg(feature = "laotian")]
+    #[test]
+    fn expect_to_match_lao_spelling_variants() {
+        let mut known = KnownWords::new();
+        assert!(known.add(Language::Laotian, "ລູກຫຼ້າ"));
+        assert!(!known.add(Language::Laotian, "ລູກຫລ້າ"));
+
+        assert!(known.contains(Language::Laotian, "ລູກຫລ້າ"));
+        assert!(known.contains(Language::Laotian, "ລູກຫຼ້າ"));
+        assert!(known.is_known(Language::Laotian, "ລູກຫລ້າ", &word(&["ລູກຫລ້າ"], 1)));
+        assert_eq!(
+            known.words(Language::Laotian).collect::<Vec<_>>(),
+            vec!["ລູກຫລ້າ"]
+        );
+
+        assert!(known.remove(Language::Laotian, "ລູກຫຼ້າ"));
+        assert!(!known.contains(Language::Laotian, "ລູກຫລ້າ"));
+    }
+
+    #[test]
+    fn expect_to_save_and_load_json() {
+        let mut known = KnownWords::new();
+        known.add(Language::Chinese, "喜歡");
+
+        let json = known.to_json().unwrap();
+        assert_eq!(json, r#"{"words":{"Chinese":["喜歡"]}}"#);
+        assert_eq!(KnownWords::from_json(&json).unwrap(), known);
+    }
+
+    #[test]
+    fn expect_to_import_csv() {
+        let csv = "written,pronunciations,translations,count,parts_of_speech\n\"喜歡,喜欢\",\"xi3,huan5\",to like,1,\n";
+
+        let options = ExportOptions::word_fields().with_escaped_lists(true);
+
+        let mut known = KnownWords::new();
+        assert_eq!(
+            known
+                .import_csv(Language::Chinese, csv.as_bytes(), &options)
+                .unwrap(),
+            2
+        );
+        assert!(known.contains(Language::Chinese, "喜欢"));
+    }
+
+    #[test]
+    fn expect_to_import_csv_export_with_escaped_separator() {
+        use crate::export::{Column, Export};
+
+        let words = WordParserResult::from([
+            ("喜歡".to_string(), word(&["喜歡", "喜欢"], 1)),
+            ("一,二".to_string(), word(&["一,二"], 1)),
+        ]);
+        // the written column isn't the first one
+        let options = ExportOptions::word_fields()
+            .with_columns(&[Column::Count, Column::Written])
+            .with_escaped_lists(true);
+        let csv = words.export(Format::Csv, &options).unwrap();
+        assert!(csv.contains("一\\,二"));
+
+        let mut known = KnownWords::new();
+        assert_eq!(
+            known
+                .import_csv(Language::Chinese, csv.as_bytes(), &options)
+                .unwrap(),
+            3
+        );
+        assert!(known.contains(Language::Chinese, "一,二"));
+        assert!(!known.contains(Language::Chinese, "一"));
+        assert!(known.contains(Language::Chinese, "喜欢"));
+    }
+
+    #[test]
+    fn expect_to_diff_parse_result() {
+        let mut known = KnownWords::new();
+        known.add(Language::Chinese, "喜欢");
+
+        let words = WordParserResult::from([
+            ("喜歡".to_string(), word(&["喜歡", "喜欢"], 3)),
+            ("開車".to_string(), word(&["開車", "开车"], 1)),
+        ]);
+
+        let diff = known.diff(Language::Chinese, &words);
+        assert_eq!(diff.new_words.keys().collect::<Vec<_>>(), vec!["開車"]);
+        assert_eq!(diff.known_tokens, 3);
+        assert_eq!(diff.total_tokens, 4);
+        assert_eq!(diff.coverage, 75.0);
+    }
+}
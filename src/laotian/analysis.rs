@@ -0,0 +1,195 @@
+use super::normalize::NormalizedText;
+use super::syllable::{
+    self, ConsonantClass, Segment, Syllable, SyllableType, Tone, ToneMark, VowelLength,
+};
+use serde::Serialize;
+
+/// Analysis of a syllable explaining how its tone is computed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SyllableAnalysis {
+    /// Syllable as written in the analyzed word. [`Syllable::analyze`] uses the canonical spelling
+    pub text: String,
+    /// Letters of the initial consonant
+    pub initial: String,
+    pub class: ConsonantClass,
+    pub vowel_length: VowelLength,
+    pub final_consonant: Option<char>,
+    pub syllable_type: SyllableType,
+    pub tone_mark: Option<ToneMark>,
+    pub tone: Tone,
+    /// Human readable explanation of the tone rule which has been applied
+    pub explanation: String,
+}
+
+impl Syllable {
+    /// Analyze the syllable to get the components used to compute its tone
+    pub fn analyze(&self) -> SyllableAnalysis {
+        SyllableAnalysis {
+            text: self.text.clone(),
+            initial: self.initial.letters.clone(),
+            class: self.class(),
+            vowel_length: self.vowel.length,
+            final_consonant: self.final_consonant,
+            syllable_type: self.syllable_type(),
+            tone_mark: self.tone_mark,
+            tone: self.tone(),
+            explanation: explain(self),
+        }
+    }
+}
+
+/// Analyze each syllable of a lao word. The text of each syllable is kept as written in the word
+///
+/// # Arguments
+///
+/// * `word` - &str
+pub fn analyze(word: &str) -> Vec<SyllableAnalysis> {
+    let normalized = NormalizedText::new(word);
+    let mut analysis = Vec::new();
+    let mut offset = 0;
+
+    for segment in syllable::split_into_syllables(&normalized.text) {
+        match segment {
+            Segment::Syllable(s) => {
+                let end = offset + s.text.len();
+                analysis.push(SyllableAnalysis {
+                    text: normalized.original_slice(offset, end).to_string(),
+                    ..s.analyze()
+                });
+                offset = end;
+            }
+            Segment::Other(other) => offset += other.len(),
+        }
+    }
+
+    analysis
+}
+
+/// Explain the tone rule applied to a syllable
+/// e.g: ກິນ -> "ກ is a middle class consonant. The syllable is live because it ends with ນ. Without tone mark the tone is low rising"
+///
+/// # Arguments
+///
+/// * `syllable` - &Syllable
+fn explain(syllable: &Syllable) -> String {
+    let class = format!(
+        "{} is a {} class consonant",
+        syllable.initial.letters,
+        class_name(syllable.class())
+    );
+
+    let length = match syllable.vowel.length {
+        VowelLength::Short => "short",
+        VowelLength::Long => "long",
+    };
+
+    let syllable_type = match (syllable.syllable_type(), syllable.final_consonant) {
+        (SyllableType::Dead, Some(c)) => format!("dead because it ends with the stop {c}"),
+        (SyllableType::Dead, None) => "dead because it ends with a short vowel".to_string(),
+        (SyllableType::Live, Some(c)) => format!("live because it ends with {c}"),
+        (SyllableType::Live, None) if syllable.vowel.glide => {
+            "live because its vowel ends with a glide".to_string()
+        }
+        (SyllableType::Live, None) => format!("live because it ends with a {length} vowel"),
+    };
+
+    let rule = match syllable.tone_mark {
+        Some(mark) => format!("With the tone mark {}", mark_name(mark)),
+        None => "Without tone mark".to_string(),
+    };
+
+    format!(
+        "{class}. The syllable is {syllable_type}. {rule} the tone is {}",
+        tone_name(syllable.tone())
+    )
+}
+
+/// Get the name of a consonant class
+///
+/// # Arguments
+///
+/// * `class` - ConsonantClass
+fn class_name(class: ConsonantClass) -> &'static str {
+    match class {
+        ConsonantClass::High => "high",
+        ConsonantClass::Middle => "middle",
+        ConsonantClass::Low => "low",
+    }
+}
+
+/// Get the name of a tone mark
+///
+/// # Arguments
+///
+/// * `mark` - ToneMark
+fn mark_name(mark: ToneMark) -> &'static str {
+    match mark {
+        ToneMark::MaiEk => "mai ek (◌່)",
+        ToneMark::MaiTho => "mai tho (◌້)",
+        ToneMark::MaiTi => "mai ti (◌໊)",
+        ToneMark::MaiChattawa => "mai chattawa (◌໋)",
+    }
+}
+
+/// Get the name of a tone
+///
+/// # Arguments
+///
+/// * `tone` - Tone
+fn tone_name(tone: Tone) -> &'static str {
+    match tone {
+        Tone::LowRising => "low rising",
+        Tone::Rising => "rising",
+        Tone::High => "high",
+        Tone::Mid => "mid",
+        Tone::HighFalling => "high falling",
+        Tone::LowFalling => "low falling",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_analyze_live_syllable() {
+        let analysis = analyze("ກິນ");
+        assert_eq!(analysis.len(), 1);
+
+        let kin = &analysis[0];
+        assert_eq!(kin.initial, "ກ");
+        assert_eq!(kin.class, ConsonantClass::Middle);
+        assert_eq!(kin.vowel_length, VowelLength::Short);
+        assert_eq!(kin.syllable_type, SyllableType::Live);
+        assert_eq!(kin.tone_mark, None);
+        assert_eq!(kin.tone, Tone::LowRising);
+        assert_eq!(
+            kin.explanation,
+            "ກ is a middle class consonant. The syllable is live because it ends with ນ. Without tone mark the tone is low rising"
+        );
+    }
+
+    #[test]
+    fn expect_to_analyze_each_syllable_of_word() {
+        let analysis = analyze("ໜັງສືບໍ່");
+
+        let texts = analysis.iter().map(|a| a.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(texts, vec!["ໜັງ", "ສື", "ບໍ່"]);
+
+        assert_eq!(analysis[0].class, ConsonantClass::High);
+        assert_eq!(analysis[1].tone, Tone::Rising);
+        assert_eq!(analysis[2].tone_mark, Some(ToneMark::MaiEk));
+        assert!(analysis[2].explanation.contains("mai ek"));
+    }
+
+    #[test]
+    fn expect_to_explain_glide_vowel() {
+        let pai = &analyze("ໄປ")[0];
+        assert_eq!(pai.vowel_length, VowelLength::Short);
+        assert_eq!(pai.syllable_type, SyllableType::Live);
+        assert_eq!(
+            pai.explanation,
+            "ປ is a middle class consonant. The syllable is live because its vowel ends with a glide. Without tone mark the tone is low rising"
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use super::syllable::{self, Segment, ToneMark};
+use crate::collation::Collator;
+use crate::word::Word;
+
+/// Order of the vowels in a lao dictionary. Vowels are identified by their IPA
+const VOWEL_ORDER: [&str; 24] = [
+    "a", "aː", "i", "iː", "ɯ", "ɯː", "u", "uː", "e", "eː", "ɛ", "ɛː", "o", "oː", "ɔ", "ɔː", "ɤ",
+    "ɤː", "iə", "ɯə", "uə", "aj", "aw", "am",
+];
+
+/// Offset added to the characters which are not lao so that they're sorted after the lao consonants
+const NON_LAO_OFFSET: u32 = 0x100;
+
+/// Key of a syllable. Syllables are compared by their initial consonant, then by their vowel,
+/// then by their final consonant and then by their tone mark
+type SyllableKey = (Vec<u32>, u32, u32, u32);
+
+/// Collator sorting lao words in the dictionary order. Unlike the code point order, the words are sorted
+/// by their initial consonant first even if they're written with a leading vowel (ເ, ແ, ໂ, ໄ, ໃ)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LaoCollator;
+
+impl Collator for LaoCollator {
+    type Key = Vec<SyllableKey>;
+
+    fn sort_key(&self, key: &str, _: &Word) -> Self::Key {
+        collation_key(key)
+    }
+}
+
+/// Compute the collation key of a lao text
+///
+/// # Arguments
+///
+/// * `text` - &str
+pub fn collation_key(text: &str) -> Vec<SyllableKey> {
+    syllable::split_into_syllables(text)
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Syllable(s) => (
+                s.initial
+                    .letters
+                    .chars()
+                    .map(|c| syllable::consonant_order(c).unwrap_or(u32::MAX))
+                    .collect(),
+                VOWEL_ORDER
+                    .iter()
+                    .position(|ipa| *ipa == s.vowel.ipa)
+                    .map_or(u32::MAX, |idx| idx as u32),
+                s.final_consonant
+                    .and_then(syllable::consonant_order)
+                    .map_or(0, |order| order + 1),
+                tone_mark_order(s.tone_mark),
+            ),
+            // characters which are not lao are sorted after the lao syllables
+            Segment::Other(other) => (
+                other.chars().map(|c| NON_LAO_OFFSET + c as u32).collect(),
+                0,
+                0,
+                0,
+            ),
+        })
+        .collect()
+}
+
+/// Get the order of a tone mark. A syllable without tone mark comes first
+///
+/// # Arguments
+///
+/// * `mark` - Option<ToneMark>
+fn tone_mark_order(mark: Option<ToneMark>) -> u32 {
+    match mark {
+        None => 0,
+        Some(ToneMark::MaiEk) => 1,
+        Some(ToneMark::MaiTho) => 2,
+        Some(ToneMark::MaiTi) => 3,
+        Some(ToneMark::MaiChattawa) => 4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collation::SortedWords;
+    use crate::word::WordParserResult;
+
+    #[test]
+    fn expect_to_sort_by_consonant_before_vowel() {
+        let mut words = vec!["ແມ່", "ເກົ້າ", "ກິນ", "ໄປ", "ກາ", "ຂ້ອຍ", "ກ່າ"];
+        words.sort_by_cached_key(|w| collation_key(w));
+
+        assert_eq!(words, vec!["ກາ", "ກ່າ", "ກິນ", "ເກົ້າ", "ຂ້ອຍ", "ໄປ", "ແມ່"]);
+    }
+
+    #[test]
+    fn expect_to_sort_parse_result() {
+        let words = WordParserResult::from([
+            ("ໄປ".to_string(), Word::default()),
+            ("ບໍ່".to_string(), Word::default()),
+        ]);
+
+        let sorted = words
+            .sorted_by(&LaoCollator)
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+
+        assert_eq!(sorted, vec!["ບໍ່", "ໄປ"]);
+    }
+}
@@ -1,12 +1,26 @@
-use crate::dictionary::{Dictionary, Initializer, Lang, Laotian};
+use crate::dictionary::{Dictionary, Initializer, Lang, LanguageData, LanguageDictionary, Laotian};
 use crate::error::DictionaryError;
-use crate::word::{Word, WordParser, WordParserResult};
+use crate::registry::LanguageTag;
+use crate::word::{
+    PartOfSpeech, Reading, Romanizer, Sense, SentenceSegmenter, TextSegment, Word, WordParser,
+    WordParserResult, segment,
+};
 use crate::{punctuation, util};
 use chamkho::wordcut_engine::create_prefix_tree;
+use normalize::NormalizedText;
 use serde::Deserialize;
 use std::collections::{BTreeMap, HashMap};
 use std::ops::Deref;
 use std::path::PathBuf;
+use syllable::Tone;
+
+pub mod analysis;
+pub mod collation;
+pub mod normalize;
+pub mod numeral;
+pub mod romanization;
+pub mod syllable;
+pub mod transliteration;
 
 /// Used for parsing the dictionnary
 #[derive(Debug, Clone, Deserialize)]
@@ -17,6 +31,12 @@ pub struct JPEnLaoItem {
     phonetic: String,
     #[serde(rename(deserialize = "English"))]
     english: String,
+    #[serde(rename(deserialize = "Type"), default)]
+    kind: String,
+}
+
+impl LanguageData for Laotian {
+    type Data = ();
 }
 
 impl Initializer<Laotian> for Dictionary<Laotian> {
@@ -32,6 +52,7 @@ impl Initializer<Laotian> for Dictionary<Laotian> {
             dict: HashMap::new(),
             punctuation: p.laotian,
             params: Lang::Laotian(None),
+            data: (),
         })
     }
 
@@ -45,7 +66,7 @@ impl Initializer<Laotian> for Dictionary<Laotian> {
     ///
     /// * `&mut self` - Self
     fn load(&mut self, path: PathBuf) -> Result<(), DictionaryError> {
-        let mut dict = HashMap::new();
+        let mut dict: HashMap<String, Word> = HashMap::new();
         let mut chamkho_tree = Vec::new();
 
         // reading the csv
@@ -55,18 +76,34 @@ impl Initializer<Laotian> for Dictionary<Laotian> {
                 continue;
             };
 
-            let key = record.lao.trim().to_string();
-            // create a definition from the record
-            let def = Word {
-                written: vec![key.clone()],
-                pronunciations: vec![record.phonetic.trim().to_string()],
-                translations: vec![record.english.trim().to_string()],
-                count: 0,
+            let written = record.lao.trim().to_string();
+            // spelling variants of a headword share the same entry
+            let key = normalize::normalize(&written);
+            let sense = Sense {
+                pronunciation: record.phonetic.trim().to_string(),
+                translation: record.english.trim().to_string(),
+                parts_of_speech: PartOfSpeech::parse_list(&record.kind),
             };
 
-            chamkho_tree.push(key.clone());
+            // a headword can be defined on multiple rows. Each row is a new sense of the word
+            match dict.get_mut(&key) {
+                Some(def) => {
+                    if !def.written.contains(&written) {
+                        def.written.push(written);
+                    }
+                    add_sense(def, sense);
+                }
+                None => {
+                    let mut def = Word {
+                        written: vec![written],
+                        ..Default::default()
+                    };
+                    add_sense(&mut def, sense);
 
-            dict.insert(key, def);
+                    chamkho_tree.push(key.clone());
+                    dict.insert(key, def);
+                }
+            }
         }
 
         // Create the chamkho parser instance from the laotian word that has been founded
@@ -81,24 +118,231 @@ impl Initializer<Laotian> for Dictionary<Laotian> {
     }
 }
 
-impl WordParser for Dictionary<Laotian> {
-    fn parse_sentence_into_words<S: AsRef<str>>(&self, sentence: S) -> WordParserResult {
-        let mut founded = BTreeMap::new();
-        // clean the string first
-        let cleaned_sentence = util::clean_sentence(sentence.as_ref(), &self.punctuation);
+/// Add a sense to a word. The pronunciation, the translation and the parts of speech of the sense are added to the
+/// pronunciations, translations and parts of speech of the word if they're not already present
+///
+/// # Arguments
+///
+/// * `word` - &mut Word
+/// * `sense` - Sense
+fn add_sense(word: &mut Word, sense: Sense) {
+    if word.senses.contains(&sense) {
+        return;
+    }
+
+    if !word.pronunciations.contains(&sense.pronunciation) {
+        word.pronunciations.push(sense.pronunciation.clone());
+    }
+
+    if !word.translations.contains(&sense.translation) {
+        word.translations.push(sense.translation.clone());
+    }
+
+    for pos in &sense.parts_of_speech {
+        if !word.parts_of_speech.contains(pos) {
+            word.parts_of_speech.push(pos.clone());
+        }
+    }
+
+    word.senses.push(sense);
+}
+
+/// A token found while segmenting a laotian sentence. The text of a token is written as in the sentence
+#[derive(Debug, Clone)]
+pub(crate) enum Token<'a> {
+    /// A word with its definition when the word is present in the dictionary
+    Word(String, Option<&'a Word>),
+    /// A number written with digits or spelled out with its value
+    Numeral(String, u64),
+}
 
+impl Token<'_> {
+    /// Get the text of the token
+    pub(crate) fn text(&self) -> &str {
+        match self {
+            Token::Word(text, _) | Token::Numeral(text, _) => text,
+        }
+    }
+}
+
+impl Dictionary<Laotian> {
+    /// Segment a sentence into tokens with the chamkho parser. Numbers are only parsed on the spans which
+    /// are not covered by a word of the dictionary e.g: ສີ່ແຍກ stays a word but ສາມສິບຫ້າ is 35.
+    /// The sentence is segmented in its canonical spelling.
+    ///
+    /// # Arguments
+    ///
+    /// * `sentence` - &str
+    pub(crate) fn segment(&self, sentence: &str) -> Vec<Token<'_>> {
+        let mut tokens = Vec::new();
         // get a list of laotian word from the sentence
-        let Lang::Laotian(parser) = &self.params else {
-            return founded;
+        let Lang::Laotian(Some(parser)) = &self.params else {
+            return tokens;
         };
 
-        let parser = parser.as_ref().expect("Expect to have the parser set");
-        let words = parser.segment_into_strings(&cleaned_sentence);
-        for word in words {
-            if let Some(item) = self.dict.get(&word) {
-                founded.insert(word.clone(), item.to_owned());
-                // add to the counter
-                self.insert_word(&mut founded, word, item.clone());
+        // clean the string first
+        let cleaned_sentence = util::clean_sentence(sentence, &self.punctuation);
+        let normalized = NormalizedText::new(&cleaned_sentence);
+        let ranges = parser
+            .segment_into_byte_ranges(&normalized.text)
+            .into_iter()
+            .map(|range| (range.s, range.e))
+            .collect::<Vec<_>>();
+
+        // chamkho may cut a syllable e.g: ສາມ -> ສາ, ມ
+        let mut boundaries = vec![0];
+        for segment in syllable::split_into_syllables(&normalized.text) {
+            let len = match segment {
+                syllable::Segment::Syllable(s) => s.text.len(),
+                syllable::Segment::Other(other) => other.len(),
+            };
+            boundaries.push(boundaries[boundaries.len() - 1] + len);
+        }
+
+        // the words of the dictionary which aren't numbers and which don't cut a syllable cover their span
+        // e.g: ສີ່ແຍກ
+        let mut covered = ranges
+            .iter()
+            .filter(|(s, e)| {
+                let text = &normalized.text[*s..*e];
+                boundaries.contains(s)
+                    && boundaries.contains(e)
+                    && self.dict.contains_key(text)
+                    && !numeral::is_number(text)
+            })
+            .copied()
+            .collect::<Vec<_>>();
+
+        covered.push((normalized.text.len(), normalized.text.len()));
+
+        let mut numerals = Vec::new();
+        let mut cursor = 0;
+        for (start, end) in covered {
+            for numeral in numeral::find_numerals(&normalized.text[cursor..start]) {
+                let (s, e) = (cursor + numeral.start, cursor + numeral.end);
+                // a number which is a word of the dictionary keeps its definitions e.g: ຮ້ອຍ
+                if !(ranges.contains(&(s, e)) && self.dict.contains_key(&normalized.text[s..e])) {
+                    numerals.push((s, e, numeral.value));
+                }
+            }
+            cursor = end;
+        }
+
+        let mut cursor = 0;
+        for (start, end, value) in numerals {
+            self.push_words(&normalized, &ranges, cursor, start, &mut tokens);
+            tokens.push(Token::Numeral(
+                normalized.original_slice(start, end).to_string(),
+                value,
+            ));
+            cursor = end;
+        }
+
+        self.push_words(
+            &normalized,
+            &ranges,
+            cursor,
+            normalized.text.len(),
+            &mut tokens,
+        );
+
+        tokens
+    }
+
+    /// Push the words of the ranges which are within a slice of a normalized sentence
+    ///
+    /// # Arguments
+    ///
+    /// * `normalized` - &NormalizedText
+    /// * `ranges` - &[(usize, usize)]
+    /// * `start` - usize
+    /// * `end` - usize
+    /// * `tokens` - &mut Vec<Token>
+    fn push_words<'a>(
+        &'a self,
+        normalized: &NormalizedText,
+        ranges: &[(usize, usize)],
+        start: usize,
+        end: usize,
+        tokens: &mut Vec<Token<'a>>,
+    ) {
+        for (s, e) in ranges {
+            let (s, e) = (start.max(*s), end.min(*e));
+            if s < e {
+                self.push_word(normalized, s, e, tokens);
+            }
+        }
+    }
+
+    /// Push a word of a normalized sentence. A repetition mark (ໆ) repeats the previous token unless
+    /// the word with the repetition mark is present in the dictionary.
+    ///
+    /// # Arguments
+    ///
+    /// * `normalized` - &NormalizedText
+    /// * `start` - usize
+    /// * `end` - usize
+    /// * `tokens` - &mut Vec<Token>
+    fn push_word<'a>(
+        &'a self,
+        normalized: &NormalizedText,
+        start: usize,
+        end: usize,
+        tokens: &mut Vec<Token<'a>>,
+    ) {
+        let text = &normalized.text[start..end];
+        if let Some(item) = self.dict.get(text) {
+            tokens.push(Token::Word(
+                normalized.original_slice(start, end).to_string(),
+                Some(item),
+            ));
+            return;
+        }
+
+        let word = text.trim_end_matches(numeral::REPETITION_MARK);
+        if !word.is_empty() {
+            tokens.push(Token::Word(
+                normalized
+                    .original_slice(start, start + word.len())
+                    .to_string(),
+                self.dict.get(word),
+            ));
+        }
+
+        for _ in text[word.len()..].chars() {
+            if let Some(previous) = tokens.last().cloned() {
+                tokens.push(previous);
+            }
+        }
+    }
+}
+
+/// Create the word of a number
+///
+/// # Arguments
+///
+/// * `text` - String
+/// * `value` - u64
+fn numeral_word(text: String, value: u64) -> Word {
+    Word {
+        written: vec![text],
+        translations: vec![value.to_string()],
+        parts_of_speech: vec![PartOfSpeech::Numeral],
+        ..Default::default()
+    }
+}
+
+impl WordParser for Dictionary<Laotian> {
+    fn parse_sentence_into_words<S: AsRef<str>>(&self, sentence: S) -> WordParserResult {
+        let mut founded = BTreeMap::new();
+
+        for token in self.segment(sentence.as_ref()) {
+            match token {
+                Token::Word(word, Some(item)) => self.insert_word(&mut founded, word, item.clone()),
+                Token::Numeral(text, value) => {
+                    self.insert_word(&mut founded, text.clone(), numeral_word(text, value))
+                }
+                Token::Word(_, None) => {}
             }
         }
 
@@ -106,6 +350,98 @@ impl WordParser for Dictionary<Laotian> {
     }
 }
 
+impl SentenceSegmenter for Dictionary<Laotian> {
+    fn segment_sentence<S: AsRef<str>>(&self, sentence: S) -> Vec<TextSegment> {
+        let mut segments = Vec::new();
+
+        for (run, punctuation) in segment::split_punctuation(sentence.as_ref(), &self.punctuation) {
+            if punctuation {
+                segments.push(TextSegment {
+                    text: run.to_string(),
+                    word: None,
+                    punctuation,
+                });
+                continue;
+            }
+
+            let mut rest = run;
+            for token in self.segment(run) {
+                let (mut text, word) = match token {
+                    Token::Word(text, word) => (text, word.cloned()),
+                    Token::Numeral(text, value) => (text.clone(), Some(numeral_word(text, value))),
+                };
+
+                // a repeated word is written with the repetition mark in the sentence
+                if !rest.starts_with(text.as_str()) && rest.starts_with(numeral::REPETITION_MARK) {
+                    text = numeral::REPETITION_MARK.to_string();
+                }
+                rest = rest.strip_prefix(text.as_str()).unwrap_or(rest);
+
+                segments.push(TextSegment {
+                    text,
+                    word,
+                    punctuation: false,
+                });
+            }
+        }
+
+        segments
+    }
+}
+
+impl Romanizer for Dictionary<Laotian> {
+    fn readings(&self, segment: &TextSegment) -> Vec<Reading> {
+        if segment.punctuation {
+            return Vec::new();
+        }
+
+        // unknown words are romanized too
+        syllable::split_into_syllables(&segment.text)
+            .iter()
+            .map(|segment| match segment {
+                syllable::Segment::Syllable(s) => Reading {
+                    text: s.romanize(),
+                    tone: Some(tone_name(s.tone()).to_string()),
+                },
+                syllable::Segment::Other(other) => Reading {
+                    text: other.clone(),
+                    tone: None,
+                },
+            })
+            .collect()
+    }
+}
+
+/// Get the name of a lao tone used by the readings e.g: tone-mid
+///
+/// # Arguments
+///
+/// * `tone` - Tone
+fn tone_name(tone: Tone) -> &'static str {
+    match tone {
+        Tone::LowRising => "tone-low-rising",
+        Tone::Rising => "tone-rising",
+        Tone::High => "tone-high",
+        Tone::Mid => "tone-mid",
+        Tone::HighFalling => "tone-high-falling",
+        Tone::LowFalling => "tone-low-falling",
+    }
+}
+
+impl LanguageDictionary for Dictionary<Laotian> {
+    fn tag(&self) -> LanguageTag {
+        LanguageTag::Lao
+    }
+
+    fn lookup(&self, word: &str) -> Option<&Word> {
+        self.dict.get(&normalize::normalize(word))
+    }
+
+    fn parse(&self, sentence: &str) -> WordParserResult {
+        self.parse_sentence_into_words(sentence)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::LazyLock;
@@ -136,8 +472,8 @@ mod tests {
 
         let item = item.unwrap();
         assert_eq!(item.written.first().unwrap(), "ຮັກ");
-        assert_eq!(item.pronunciations.get(0).unwrap(), "hak");
-        assert_eq!(item.translations.get(0).unwrap(), "love");
+        assert_eq!(item.pronunciations.first().unwrap(), "hak");
+        assert_eq!(item.translations.first().unwrap(), "love");
     }
 
     #[test]
@@ -152,6 +488,220 @@ mod tests {
         assert_eq!(baby.translations, vec!["youngest child"]);
     }
 
+    #[test]
+    fn expect_to_search_word_by_english_translation() {
+        let index = DICTIONARY.english_index();
+        let results = index.search_english("youngest child");
+
+        let baby = results.iter().find(|res| res.key == "ລູກຫລ້າ").unwrap();
+        assert_eq!(baby.sense, "youngest child");
+        assert_eq!(baby.score, results.first().unwrap().score);
+
+        // whole sense matches come first, ordered by the length of the word then by the word
+        let keys = results
+            .iter()
+            .take(7)
+            .map(|res| res.key)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            vec![
+                "ລູກປາຍ",
+                "ບຸດຫລ້າ",
+                "ລູກຫລ້າ",
+                "ນ້ອງຫລ້າ",
+                "ລູກສຸດທ້ອງ",
+                "ລູກສຸດທ້າຍ",
+                "ໂປດົກ"
+            ]
+        );
+        assert!(results[..6].iter().all(|res| res.score == 2.0));
+        assert!(results[6].score < 2.0);
+        assert!(
+            results
+                .windows(2)
+                .all(|pair| pair[0].score >= pair[1].score)
+        );
+    }
+
+    #[test]
+    fn expect_to_merge_senses_of_duplicated_headwords() {
+        let spine = DICTIONARY.dict.get("ກະດູກສັນຫລັງ").unwrap();
+        assert_eq!(spine.translations, vec!["spine", "backbone"]);
+        assert_eq!(spine.pronunciations, vec!["kádouksanlang"]);
+        assert_eq!(spine.senses.len(), 2);
+        assert_eq!(spine.senses[1].translation, "backbone");
+        assert_eq!(spine.senses[1].parts_of_speech, vec![PartOfSpeech::Noun]);
+
+        let guess = DICTIONARY.dict.get("ກະການ").unwrap();
+        assert_eq!(guess.pronunciations, vec!["kákan", "kákanvai"]);
+        assert_eq!(guess.senses[1].pronunciation, "kákanvai");
+        assert_eq!(guess.senses[1].translation, "estimate");
+    }
+
+    #[test]
+    fn expect_to_filter_words_by_part_of_speech() {
+        use crate::word::PartOfSpeechFilter;
+
+        let words = DICTIONARY.parse_sentence_into_words("ຂ້ອຍຮັກເຈົ້າ");
+        assert_eq!(
+            DICTIONARY.dict.get("ຮັກ").unwrap().parts_of_speech,
+            vec![PartOfSpeech::Verb]
+        );
+
+        let verbs = words.filter_by_part_of_speech(&[PartOfSpeech::Verb]);
+        assert!(verbs.contains_key("ຮັກ"));
+        assert!(
+            verbs
+                .values()
+                .all(|word| word.parts_of_speech.contains(&PartOfSpeech::Verb))
+        );
+    }
+
+    #[test]
+    fn expect_to_search_word_by_romanization() {
+        let index = DICTIONARY.romanization_index();
+
+        let results = index.search("luk lar");
+        assert!(results.iter().any(|res| res.key == "ລູກຫລ້າ"));
+
+        let results = index.search("kin");
+        assert!(results.iter().any(|res| res.key == "ກິນ"));
+        assert!(results.iter().all(|res| res.exact));
+    }
+
+    #[test]
+    fn expect_to_match_spelling_variants() {
+        let words = DICTIONARY.parse_sentence_into_words("ລູກຫຼ້າຢາກອ່ານໜັງສື");
+
+        let baby = words.get("ລູກຫຼ້າ").unwrap();
+        assert_eq!(baby.written.first().unwrap(), "ລູກຫລ້າ");
+        assert_eq!(baby.translations, vec!["youngest child"]);
+
+        assert!(words.contains_key("ໜັງສື"));
+        assert!(DICTIONARY.lookup("ລູກຫຼ້າ").is_some());
+    }
+
+    #[test]
+    fn expect_to_transliterate_unknown_words() {
+        let words = DICTIONARY.transliterate("ລູກຫລ້າຢາກກິນ");
+
+        let baby = words.iter().find(|w| w.text == "ລູກຫລ້າ").unwrap();
+        assert!(baby.word.is_some());
+        assert_eq!(baby.romanization, "loukla");
+
+        // ຄວ is a cluster with the medial ວ. The word isn't in the dictionary
+        let unknown = DICTIONARY.transliterate("ຄວີນ");
+        assert_eq!(unknown.len(), 1);
+        assert!(unknown[0].word.is_none());
+        assert_eq!(unknown[0].romanization, "khouin");
+        assert_eq!(unknown[0].ipa, "kʰwiːn˥");
+        assert_eq!(transliteration::romanize("ຫຼາຍ"), "lay");
+
+        // ກວ with the mai ek tone mark
+        let than = DICTIONARY.transliterate("ກວ່າ");
+        assert_eq!(than[0].romanization, "koua");
+        assert_eq!(than[0].ipa, "kwaː˧");
+
+        // mai tho on a middle class consonant
+        assert_eq!(transliteration::romanize("ແກ້ວ"), "kèo");
+        assert_eq!(transliteration::to_ipa("ແກ້ວ"), "kɛːw˥˧");
+    }
+
+    #[test]
+    fn expect_to_parse_numerals() {
+        let words = DICTIONARY.parse_sentence_into_words("ມີ໓໐ຄົນ ແລະ ສາມສິບຫ້າຄົນ");
+
+        let thirty = words.get("໓໐").unwrap();
+        assert_eq!(thirty.translations, vec!["30"]);
+        assert_eq!(thirty.parts_of_speech, vec![PartOfSpeech::Numeral]);
+
+        let thirty_five = words.get("ສາມສິບຫ້າ").unwrap();
+        assert_eq!(thirty_five.translations, vec!["35"]);
+    }
+
+    #[test]
+    fn expect_compound_words_to_win_over_numerals() {
+        let tokens = DICTIONARY.segment("ສີ່ແຍກ ສາມລໍ້ ຮ້ອຍ");
+        let texts = tokens.iter().map(Token::text).collect::<Vec<_>>();
+        assert_eq!(texts, vec!["ສີ່ແຍກ", "ສາມລໍ້", "ຮ້ອຍ"]);
+        assert!(tokens.iter().all(|t| matches!(t, Token::Word(_, Some(_)))));
+
+        let words = DICTIONARY.parse_sentence_into_words("ສີ່ແຍກ ສາມລໍ້ ຮ້ອຍ");
+        assert_eq!(
+            words.get("ສີ່ແຍກ").unwrap().translations,
+            vec!["crossroads", "Intersection"]
+        );
+        assert_eq!(
+            words.get("ສາມລໍ້").unwrap().translations,
+            vec!["tricycle taxi"]
+        );
+        assert_eq!(
+            words.get("ຮ້ອຍ").unwrap().translations,
+            vec!["One hundred", "100"]
+        );
+    }
+
+    #[test]
+    fn expect_to_parse_numerals_with_et() {
+        let words = DICTIONARY.parse_sentence_into_words("ມີພັນເອັດຄົນ");
+        assert_eq!(words.get("ພັນເອັດ").unwrap().translations, vec!["1001"]);
+
+        let words = DICTIONARY.parse_sentence_into_words("ມີສາມຮ້ອຍເອັດຄົນ");
+        assert_eq!(words.get("ສາມຮ້ອຍເອັດ").unwrap().translations, vec!["301"]);
+
+        // the number is a word of the dictionary
+        let words = DICTIONARY.parse_sentence_into_words("ຮ້ອຍເອັດ");
+        assert_eq!(
+            words.get("ຮ້ອຍເອັດ").unwrap().translations,
+            vec!["One hundred and one"]
+        );
+    }
+
+    #[test]
+    fn expect_to_count_repeated_word() {
+        let words = DICTIONARY.parse_sentence_into_words("ຮັກ ແລະ ຮັກ ແລະ ຮັກ");
+
+        assert_eq!(words.get("ຮັກ").unwrap().count, 3);
+        assert_eq!(words.get("ແລະ").unwrap().count, 2);
+    }
+
+    #[test]
+    fn expect_to_repeat_word_with_repetition_mark() {
+        let words = DICTIONARY.parse_sentence_into_words("ຮັກໆ");
+
+        assert_eq!(words.get("ຮັກ").unwrap().count, 2);
+    }
+
+    #[test]
+    fn expect_to_segment_sentence_in_order() {
+        let segments = DICTIONARY.segment_sentence("ລູກຫລ້າຢາກກິນ ຮັກໆ");
+        let texts = segments.iter().map(|s| s.text.as_str()).collect::<Vec<_>>();
+
+        assert_eq!(texts, vec!["ລູກຫລ້າ", "ຢາກກິນ", " ", "ຮັກ", "ໆ"]);
+        assert!(segments[2].punctuation);
+        assert_eq!(
+            segments[4].word.as_ref().unwrap().translations,
+            segments[3].word.as_ref().unwrap().translations
+        );
+    }
+
+    #[test]
+    fn expect_to_export_csv_in_lao_order() {
+        use crate::collation::SortedWords;
+
+        let words = DICTIONARY.parse_sentence_into_words("ແມ່ໄປຕະຫລາດ");
+        let csv = words.to_csv_sorted_by(&collation::LaoCollator).unwrap();
+
+        let written = csv
+            .lines()
+            .skip(1)
+            .map(|line| line.split(',').next().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(written, vec!["ຕະຫລາດ", "ໄປ", "ແມ່"]);
+    }
+
     #[test]
     fn expect_to_not_match_anything() {
         let words = DICTIONARY.parse_sentence_into_words("hello");
@@ -0,0 +1,108 @@
+/// Spelling variants of the lao script and the canonical form they're mapped to.
+/// The canonical form is the one used by most of the entries of the bundled dictionary.
+const VARIANTS: [(char, &str); 4] = [
+    // ຫຼ -> ຫລ (subscript lo)
+    ('\u{0EBC}', "ລ"),
+    // ໜ -> ຫນ
+    ('\u{0EDC}', "ຫນ"),
+    // ໝ -> ຫມ
+    ('\u{0EDD}', "ຫມ"),
+    // ຣ -> ລ (spelling used before 1975)
+    ('\u{0EA3}', "ລ"),
+];
+
+/// A text which has been normalized. It keeps the position of each normalized character in the original text
+/// so that a slice of the normalized text can be mapped back to the original text
+#[derive(Debug, Clone)]
+pub struct NormalizedText<'a> {
+    original: &'a str,
+    pub text: String,
+    // byte offset in the original text of each byte of the normalized text
+    offsets: Vec<usize>,
+}
+
+impl<'a> NormalizedText<'a> {
+    /// Normalize a text and keep the offsets to the original text
+    ///
+    /// # Arguments
+    ///
+    /// * `original` - &str
+    pub fn new(original: &'a str) -> Self {
+        let mut text = String::with_capacity(original.len());
+        let mut offsets = Vec::with_capacity(original.len());
+
+        for (idx, c) in original.char_indices() {
+            let len = text.len();
+            match VARIANTS.iter().find(|(variant, _)| *variant == c) {
+                Some((_, canonical)) => text.push_str(canonical),
+                None => text.push(c),
+            }
+
+            offsets.extend(std::iter::repeat_n(idx, text.len() - len));
+        }
+
+        NormalizedText {
+            original,
+            text,
+            offsets,
+        }
+    }
+
+    /// Get the slice of the original text which correspond to the range of the normalized text
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - usize
+    /// * `end` - usize
+    pub fn original_slice(&self, start: usize, end: usize) -> &'a str {
+        let start = self
+            .offsets
+            .get(start)
+            .copied()
+            .unwrap_or(self.original.len());
+        let end = self
+            .offsets
+            .get(end)
+            .copied()
+            .unwrap_or(self.original.len());
+
+        &self.original[start..end.max(start)]
+    }
+}
+
+/// Map the spelling variants of a lao text to their canonical form
+/// e.g: ລູກຫຼ້າ -> ລູກຫລ້າ
+///
+/// # Arguments
+///
+/// * `text` - &str
+pub fn normalize(text: &str) -> String {
+    NormalizedText::new(text).text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_normalize_spelling_variants() {
+        assert_eq!(normalize("ລູກຫຼ້າ"), "ລູກຫລ້າ");
+        assert_eq!(normalize("ໜັງສື"), "ຫນັງສື");
+        assert_eq!(normalize("ໝາ"), "ຫມາ");
+        assert_eq!(normalize("ອາເມຣິກາ"), "ອາເມລິກາ");
+        assert_eq!(normalize("ກິນ"), "ກິນ");
+    }
+
+    #[test]
+    fn expect_to_map_normalized_slice_to_original() {
+        let original = "ກິນໜັງສື";
+        let normalized = NormalizedText::new(original);
+
+        let start = "ກິນ".len();
+        assert_eq!(normalized.original_slice(0, start), "ກິນ");
+        assert_eq!(
+            normalized.original_slice(start, normalized.text.len()),
+            "ໜັງສື"
+        );
+    }
+}
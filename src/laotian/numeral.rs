@@ -0,0 +1,347 @@
+use super::normalize;
+use super::syllable::{self, Segment};
+
+/// Number words with their value. The words are written in their canonical spelling
+const DIGIT_WORDS: [(&str, u64); 11] = [
+    ("ສູນ", 0),
+    ("ນຶ່ງ", 1),
+    ("ຫນຶ່ງ", 1),
+    ("ສອງ", 2),
+    ("ສາມ", 3),
+    ("ສີ່", 4),
+    ("ຫ້າ", 5),
+    ("ຫົກ", 6),
+    ("ເຈັດ", 7),
+    ("ແປດ", 8),
+    ("ເກົ້າ", 9),
+];
+
+/// Multipliers which are added to the current group of the number e.g: ສາມຮ້ອຍ -> 300
+const MULTIPLIERS: [(&str, u64); 5] = [
+    ("ສິບ", 10),
+    ("ຮ້ອຍ", 100),
+    ("ພັນ", 1_000),
+    ("ຫມື່ນ", 10_000),
+    ("ແສນ", 100_000),
+];
+
+/// Multipliers which are applied to the whole number which precede them e.g: ສອງແສນລ້ານ
+const LARGE_MULTIPLIERS: [(&str, u64); 2] = [("ລ້ານ", 1_000_000), ("ຕື້", 1_000_000_000)];
+
+/// Number words which have another meaning when they're used alone e.g: ແສນ (very), ຊາວ (people)
+const AMBIGUOUS_WORDS: [&str; 6] = ["ພັນ", "ຊາວ", "ຫມື່ນ", "ແສນ", "ລ້ານ", "ຕື້"];
+
+/// The repetition mark which repeats the previous word e.g: ຊ້າໆ -> ຊ້າ ຊ້າ
+pub const REPETITION_MARK: char = 'ໆ';
+
+/// A number found in a text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Numeral {
+    /// Byte offset of the start of the number in the text
+    pub start: usize,
+    /// Byte offset of the end of the number in the text
+    pub end: usize,
+    pub value: u64,
+}
+
+/// Value of a syllable of a spelled out number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumberWord {
+    Digit(u64),
+    /// ເອັດ is the "one" used after the tens e.g: ສິບເອັດ -> 11
+    Et,
+    /// ຊາວ is "twenty"
+    Twenty,
+    Multiplier(u64),
+    LargeMultiplier(u64),
+}
+
+/// Accumulator used to compute the value of a spelled out number
+#[derive(Debug, Default)]
+struct NumberBuilder {
+    total: u64,
+    current: u64,
+    digit: Option<u64>,
+    words: usize,
+}
+
+impl NumberBuilder {
+    /// Add a word to the number. Return false if the word can't follow the previous words
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - NumberWord
+    fn push(&mut self, word: NumberWord) -> bool {
+        match word {
+            // two digits can't follow each other e.g: ສອງສາມ is "two three"
+            NumberWord::Digit(_) | NumberWord::Twenty if self.digit.is_some() => return false,
+            NumberWord::Digit(d) => self.digit = Some(d),
+            // ເອັດ follows a multiple of ten e.g: ສິບເອັດ -> 11, ຮ້ອຍເອັດ -> 101
+            NumberWord::Et
+                if self.digit.is_some()
+                    || self.total + self.current == 0
+                    || !self.current.is_multiple_of(10) =>
+            {
+                return false;
+            }
+            NumberWord::Et => self.current += 1,
+            NumberWord::Twenty => self.current += 20,
+            NumberWord::Multiplier(m) => {
+                self.current += self.digit.take().unwrap_or(1) * m;
+            }
+            NumberWord::LargeMultiplier(m) => {
+                let value = self.current + self.digit.take().unwrap_or(0);
+                self.total = (self.total + value.max(1)).saturating_mul(m);
+                self.current = 0;
+            }
+        }
+
+        self.words += 1;
+        true
+    }
+
+    /// Get the value of the number
+    fn value(&self) -> u64 {
+        self.total + self.current + self.digit.unwrap_or(0)
+    }
+}
+
+/// Parse a text which only contains a number written with digits (lao, thai or arabic) or with words
+/// e.g: ໒໕ -> 25, ສາມສິບຫ້າ -> 35
+///
+/// # Arguments
+///
+/// * `text` - &str
+pub fn parse_numeral(text: &str) -> Option<u64> {
+    let text = normalize::normalize(text.trim());
+    let numerals = find_numerals(&text);
+
+    match numerals.as_slice() {
+        [numeral] if numeral.start == 0 && numeral.end == text.len() => Some(numeral.value),
+        _ => None,
+    }
+}
+
+/// Check whether a text is only made of number words or digits e.g: ສາມສິບ, ໒໕
+///
+/// # Arguments
+///
+/// * `text` - &str
+pub fn is_number(text: &str) -> bool {
+    let segments = syllable::split_into_syllables(text);
+
+    !segments.is_empty()
+        && segments.iter().all(|segment| match segment {
+            Segment::Syllable(s) => number_word(&s.text).is_some(),
+            Segment::Other(other) => other.chars().all(|c| digit_value(c).is_some()),
+        })
+}
+
+/// Find the numbers written in a lao text. The text is expected to be written in its canonical spelling.
+/// Numbers are either written with digits (lao, thai or arabic) or spelled out
+///
+/// # Arguments
+///
+/// * `text` - &str
+pub fn find_numerals(text: &str) -> Vec<Numeral> {
+    let mut numerals = Vec::new();
+    let mut offset = 0;
+
+    // a spelled out number being built with its start offset
+    let mut current: Option<(usize, NumberBuilder)> = None;
+    for segment in syllable::split_into_syllables(text) {
+        let segment_text = match &segment {
+            Segment::Syllable(s) => s.text.clone(),
+            Segment::Other(other) => other.clone(),
+        };
+
+        let word = match &segment {
+            Segment::Syllable(s) => number_word(&s.text),
+            Segment::Other(_) => None,
+        };
+
+        let accepted = match (word, current.as_mut()) {
+            (Some(word), Some((_, builder))) => builder.push(word),
+            _ => false,
+        };
+
+        if !accepted {
+            push_spelled_numeral(&mut numerals, current.take(), offset, text);
+
+            match (word, &segment) {
+                (Some(word), _) => {
+                    let mut builder = NumberBuilder::default();
+                    if builder.push(word) {
+                        current = Some((offset, builder));
+                    }
+                }
+                (None, Segment::Other(other)) => numerals.extend(find_digits(other, offset)),
+                (None, Segment::Syllable(_)) => {}
+            }
+        }
+
+        offset += segment_text.len();
+    }
+
+    push_spelled_numeral(&mut numerals, current.take(), offset, text);
+
+    numerals
+}
+
+/// Push a spelled out number if it's not a single ambiguous word
+///
+/// # Arguments
+///
+/// * `numerals` - &mut Vec<Numeral>
+/// * `current` - Option<(usize, NumberBuilder)>
+/// * `end` - usize
+/// * `text` - &str
+fn push_spelled_numeral(
+    numerals: &mut Vec<Numeral>,
+    current: Option<(usize, NumberBuilder)>,
+    end: usize,
+    text: &str,
+) {
+    let Some((start, builder)) = current else {
+        return;
+    };
+
+    if builder.words == 1 && AMBIGUOUS_WORDS.contains(&&text[start..end]) {
+        return;
+    }
+
+    numerals.push(Numeral {
+        start,
+        end,
+        value: builder.value(),
+    });
+}
+
+/// Find the numbers written with digits in a text which does not contains any lao syllable
+///
+/// # Arguments
+///
+/// * `text` - &str
+/// * `offset` - usize
+fn find_digits(text: &str, offset: usize) -> Vec<Numeral> {
+    let mut numerals: Vec<Numeral> = Vec::new();
+
+    for (idx, c) in text.char_indices() {
+        let Some(d) = digit_value(c) else {
+            continue;
+        };
+
+        let start = offset + idx;
+        let end = start + c.len_utf8();
+        match numerals.last_mut() {
+            Some(numeral) if numeral.end == start => {
+                numeral.end = end;
+                numeral.value = numeral.value.saturating_mul(10).saturating_add(d);
+            }
+            _ => numerals.push(Numeral {
+                start,
+                end,
+                value: d,
+            }),
+        }
+    }
+
+    numerals
+}
+
+/// Get the value of a lao, thai or arabic digit
+///
+/// # Arguments
+///
+/// * `c` - char
+fn digit_value(c: char) -> Option<u64> {
+    match c {
+        '໐'..='໙' => Some(c as u64 - '໐' as u64),
+        '๐'..='๙' => Some(c as u64 - '๐' as u64),
+        '0'..='9' => Some(c as u64 - '0' as u64),
+        _ => None,
+    }
+}
+
+/// Get the number word of a syllable
+///
+/// # Arguments
+///
+/// * `syllable` - &str
+fn number_word(syllable: &str) -> Option<NumberWord> {
+    let find = |words: &[(&str, u64)]| {
+        words
+            .iter()
+            .find(|(word, _)| *word == syllable)
+            .map(|(_, value)| *value)
+    };
+
+    if let Some(d) = find(&DIGIT_WORDS) {
+        return Some(NumberWord::Digit(d));
+    }
+
+    if let Some(m) = find(&MULTIPLIERS) {
+        return Some(NumberWord::Multiplier(m));
+    }
+
+    if let Some(m) = find(&LARGE_MULTIPLIERS) {
+        return Some(NumberWord::LargeMultiplier(m));
+    }
+
+    match syllable {
+        "ເອັດ" => Some(NumberWord::Et),
+        "ຊາວ" => Some(NumberWord::Twenty),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_parse_digits() {
+        assert_eq!(parse_numeral("໒໕"), Some(25));
+        assert_eq!(parse_numeral("๑๒"), Some(12));
+        assert_eq!(parse_numeral("2024"), Some(2024));
+    }
+
+    #[test]
+    fn expect_to_parse_spelled_out_numbers() {
+        assert_eq!(parse_numeral("ສາມສິບ"), Some(30));
+        assert_eq!(parse_numeral("ສິບເອັດ"), Some(11));
+        assert_eq!(parse_numeral("ຊາວຫ້າ"), Some(25));
+        assert_eq!(parse_numeral("ສອງພັນສາມຮ້ອຍສີ່ສິບເອັດ"), Some(2341));
+        assert_eq!(parse_numeral("ສາມລ້ານສອງແສນ"), Some(3_200_000));
+        assert_eq!(parse_numeral("ໜຶ່ງ"), Some(1));
+        assert_eq!(parse_numeral("ກິນ"), None);
+    }
+
+    #[test]
+    fn expect_to_parse_et_after_multiples_of_ten() {
+        assert_eq!(parse_numeral("ຊາວເອັດ"), Some(21));
+        assert_eq!(parse_numeral("ຮ້ອຍເອັດ"), Some(101));
+        assert_eq!(parse_numeral("ພັນເອັດ"), Some(1001));
+        assert_eq!(parse_numeral("ເອັດ"), None);
+        assert_eq!(parse_numeral("ສິບເອັດເອັດ"), None);
+    }
+
+    #[test]
+    fn expect_to_find_numerals_in_text() {
+        let text = "ມີສາມສິບຄົນ໒໕ປີ";
+        let numerals = find_numerals(text);
+
+        assert_eq!(numerals.len(), 2);
+        assert_eq!(&text[numerals[0].start..numerals[0].end], "ສາມສິບ");
+        assert_eq!(numerals[0].value, 30);
+        assert_eq!(&text[numerals[1].start..numerals[1].end], "໒໕");
+        assert_eq!(numerals[1].value, 25);
+    }
+
+    #[test]
+    fn expect_to_ignore_ambiguous_words() {
+        assert!(find_numerals("ຊາວບ້ານ").is_empty());
+        assert!(find_numerals("ສາມາດ").is_empty());
+        assert_eq!(find_numerals("ສອງສາມ").len(), 2);
+    }
+}
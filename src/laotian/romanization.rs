@@ -0,0 +1,154 @@
+use crate::dictionary::{Dictionary, Laotian};
+use crate::word::Word;
+use std::collections::HashMap;
+
+/// A word which has been found by its romanization
+#[derive(Debug, Clone)]
+pub struct RomanizationMatch<'a> {
+    /// Key of the word in the dictionary
+    pub key: &'a str,
+    pub word: &'a Word,
+    /// Whether the romanization is written exactly as the query (diacritics and length marks included)
+    pub exact: bool,
+}
+
+/// Index over the romanized pronunciations of a laotian dictionary. This allows to search
+/// a word without a lao keyboard e.g: "kin" -> ກິນ
+pub struct RomanizationIndex<'a> {
+    words: HashMap<String, Vec<(&'a str, &'a Word)>>,
+}
+
+impl<'a> RomanizationIndex<'a> {
+    /// Build an index over the pronunciations of the given words
+    ///
+    /// # Arguments
+    ///
+    /// * `dict` - &HashMap<String, Word>
+    pub fn new(dict: &'a HashMap<String, Word>) -> Self {
+        let mut words: HashMap<String, Vec<(&'a str, &'a Word)>> = HashMap::new();
+
+        for (key, word) in dict {
+            for pronunciation in &word.pronunciations {
+                let normalized = normalize(pronunciation);
+                if normalized.is_empty() {
+                    continue;
+                }
+
+                words.entry(normalized).or_default().push((key, word));
+            }
+        }
+
+        RomanizationIndex { words }
+    }
+
+    /// Search the words which romanization match the query. Diacritics, length marks (":"), hyphens
+    /// and spaces are ignored. Words which romanization is written exactly as the query are ranked first.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - &str
+    pub fn search(&self, query: &str) -> Vec<RomanizationMatch<'a>> {
+        let Some(words) = self.words.get(&normalize(query)) else {
+            return Vec::new();
+        };
+
+        let query = query.trim().to_lowercase();
+        let mut results = words
+            .iter()
+            .map(|(key, word)| RomanizationMatch {
+                key,
+                word,
+                exact: word
+                    .pronunciations
+                    .iter()
+                    .any(|p| p.trim().to_lowercase() == query),
+            })
+            .collect::<Vec<_>>();
+
+        results.sort_by(|a, b| {
+            b.exact
+                .cmp(&a.exact)
+                .then_with(|| a.key.chars().count().cmp(&b.key.chars().count()))
+                .then_with(|| a.key.cmp(b.key))
+        });
+        results.dedup_by(|a, b| a.key == b.key);
+
+        results
+    }
+}
+
+impl Dictionary<Laotian> {
+    /// Build an index over the romanized pronunciations of the dictionary
+    pub fn romanization_index(&self) -> RomanizationIndex<'_> {
+        RomanizationIndex::new(&self.dict)
+    }
+}
+
+/// Normalize a romanization so that it can be compared regardless of the way it has been typed.
+/// e.g: "lù:k lar" -> "luklar"
+///
+/// # Arguments
+///
+/// * `text` - &str
+pub fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(remove_diacritic)
+        .filter(char::is_ascii_alphanumeric)
+        .collect()
+}
+
+/// Replace a latin vowel with a diacritic by its base vowel
+///
+/// # Arguments
+///
+/// * `c` - char
+fn remove_diacritic(c: char) -> char {
+    match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ǎ' => 'a',
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ě' | 'ε' | 'є' => 'e',
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'ĭ' | 'ǐ' => 'i',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ō' | 'ŏ' | 'ǒ' => 'o',
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ŭ' | 'ǔ' => 'u',
+        'ỳ' | 'ý' | 'ÿ' => 'y',
+        c => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(pronunciation: &str) -> Word {
+        Word {
+            pronunciations: vec![pronunciation.to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn expect_to_normalize_romanization() {
+        assert_eq!(normalize("lù:k lar"), "luklar");
+        assert_eq!(normalize("Kákan-oa"), "kakanoa");
+        assert_eq!(normalize(" ăw\u{a0}"), "aw");
+    }
+
+    #[test]
+    fn expect_to_rank_exact_romanization_first() {
+        let dict = HashMap::from([
+            ("ກະ".to_string(), word("ká")),
+            ("ກາ".to_string(), word("ka")),
+            ("ກິນ".to_string(), word("kin")),
+        ]);
+
+        let index = RomanizationIndex::new(&dict);
+        let results = index.search("ka");
+
+        let keys = results.iter().map(|r| r.key).collect::<Vec<_>>();
+        assert_eq!(keys, vec!["ກາ", "ກະ"]);
+        assert!(results[0].exact);
+        assert!(!results[1].exact);
+
+        assert!(index.search("kim").is_empty());
+    }
+}
@@ -0,0 +1,557 @@
+use super::normalize;
+use VowelLength::{Long, Short};
+use serde::Serialize;
+
+/// Class of a consonant. The class of the initial consonant is used to compute the tone of a syllable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum ConsonantClass {
+    High,
+    Middle,
+    Low,
+}
+
+/// Tone marks written above the initial consonant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum ToneMark {
+    /// ່
+    MaiEk,
+    /// ້
+    MaiTho,
+    /// ໊
+    MaiTi,
+    /// ໋
+    MaiChattawa,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum VowelLength {
+    Short,
+    Long,
+}
+
+/// A syllable is dead when it ends with a short vowel or with a stop consonant (ກ, ດ, ບ). Otherwise it's live
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum SyllableType {
+    Live,
+    Dead,
+}
+
+/// Tones of the Vientiane dialect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum Tone {
+    LowRising,
+    Rising,
+    High,
+    Mid,
+    HighFalling,
+    LowFalling,
+}
+
+impl Tone {
+    /// Get the tone letters used by the IPA
+    pub fn ipa(&self) -> &'static str {
+        match self {
+            Tone::LowRising => "˩˧",
+            Tone::Rising => "˨˦",
+            Tone::High => "˥",
+            Tone::Mid => "˧",
+            Tone::HighFalling => "˥˧",
+            Tone::LowFalling => "˧˩",
+        }
+    }
+}
+
+/// A vowel is described by the pattern used to write it around the initial consonant (C).
+/// The final consonant is marked by F when it's required and by f when it's optional
+#[derive(Debug, PartialEq, Eq)]
+pub struct Vowel {
+    pattern: &'static str,
+    pub romanization: &'static str,
+    pub ipa: &'static str,
+    pub length: VowelLength,
+    /// Vowels which end with a glide (ໄ, ໃ, ເົາ, ຳ) make a live syllable even though they're short
+    pub glide: bool,
+}
+
+impl Vowel {
+    const fn new(
+        pattern: &'static str,
+        romanization: &'static str,
+        ipa: &'static str,
+        length: VowelLength,
+    ) -> Self {
+        Vowel {
+            pattern,
+            romanization,
+            ipa,
+            length,
+            glide: false,
+        }
+    }
+
+    const fn glide(pattern: &'static str, romanization: &'static str, ipa: &'static str) -> Self {
+        Vowel {
+            pattern,
+            romanization,
+            ipa,
+            length: VowelLength::Short,
+            glide: true,
+        }
+    }
+}
+
+/// Vowels of the lao script with their BGN/PCGN romanization and their IPA
+static VOWELS: [Vowel; 34] = [
+    Vowel::new("ເCືອf", "ua", "ɯə", Long),
+    Vowel::new("ເCັຍ", "ia", "iə", Short),
+    Vowel::new("ເCຍ", "ia", "iə", Long),
+    Vowel::new("CຽF", "ia", "iə", Long),
+    Vowel::glide("ເCົາ", "ao", "aw"),
+    Vowel::new("ເCາະ", "o", "ɔ", Short),
+    Vowel::new("ເCິf", "eu", "ɤ", Short),
+    Vowel::new("ເCີf", "eu", "ɤː", Long),
+    Vowel::new("ເCະ", "é", "e", Short),
+    Vowel::new("ເCັF", "é", "e", Short),
+    Vowel::new("ເCf", "é", "eː", Long),
+    Vowel::new("ແCະ", "è", "ɛ", Short),
+    Vowel::new("ແCັF", "è", "ɛ", Short),
+    Vowel::new("ແCf", "è", "ɛː", Long),
+    Vowel::new("ໂCະ", "ô", "o", Short),
+    Vowel::new("CົF", "ô", "o", Short),
+    Vowel::new("ໂCf", "ô", "oː", Long),
+    Vowel::new("Cົວ", "oua", "uə", Long),
+    Vowel::new("CວF", "oua", "uə", Long),
+    Vowel::new("Cໍf", "o", "ɔː", Long),
+    Vowel::new("CອF", "o", "ɔː", Long),
+    Vowel::glide("ໄC", "ai", "aj"),
+    Vowel::glide("ໃC", "ai", "aj"),
+    Vowel::glide("Cຳ", "am", "am"),
+    Vowel::new("Cະ", "a", "a", Short),
+    Vowel::new("CັF", "a", "a", Short),
+    Vowel::new("Cາf", "a", "aː", Long),
+    Vowel::new("Cິf", "i", "i", Short),
+    Vowel::new("Cີf", "i", "iː", Long),
+    Vowel::new("Cຶf", "u", "ɯ", Short),
+    Vowel::new("Cືf", "u", "ɯː", Long),
+    Vowel::new("Cຸf", "ou", "u", Short),
+    Vowel::new("Cູf", "ou", "uː", Long),
+    // a consonant written without any vowel is read with an inherent "o"
+    Vowel::new("C", "o", "ɔː", Long),
+];
+
+/// Consonants with their class, their BGN/PCGN romanization and their IPA when used as an initial.
+/// ຣ is not listed as the text is normalized to ລ before the syllables are read
+const CONSONANTS: [(char, ConsonantClass, &str, &str); 26] = [
+    ('ກ', ConsonantClass::Middle, "k", "k"),
+    ('ຂ', ConsonantClass::High, "kh", "kʰ"),
+    ('ຄ', ConsonantClass::Low, "kh", "kʰ"),
+    ('ງ', ConsonantClass::Low, "ng", "ŋ"),
+    ('ຈ', ConsonantClass::Middle, "ch", "tɕ"),
+    ('ສ', ConsonantClass::High, "s", "s"),
+    ('ຊ', ConsonantClass::Low, "x", "s"),
+    ('ຍ', ConsonantClass::Low, "gn", "ɲ"),
+    ('ດ', ConsonantClass::Middle, "d", "d"),
+    ('ຕ', ConsonantClass::Middle, "t", "t"),
+    ('ຖ', ConsonantClass::High, "th", "tʰ"),
+    ('ທ', ConsonantClass::Low, "th", "tʰ"),
+    ('ນ', ConsonantClass::Low, "n", "n"),
+    ('ບ', ConsonantClass::Middle, "b", "b"),
+    ('ປ', ConsonantClass::Middle, "p", "p"),
+    ('ຜ', ConsonantClass::High, "ph", "pʰ"),
+    ('ຝ', ConsonantClass::High, "f", "f"),
+    ('ພ', ConsonantClass::Low, "ph", "pʰ"),
+    ('ຟ', ConsonantClass::Low, "f", "f"),
+    ('ມ', ConsonantClass::Low, "m", "m"),
+    ('ຢ', ConsonantClass::Middle, "y", "j"),
+    ('ລ', ConsonantClass::Low, "l", "l"),
+    ('ວ', ConsonantClass::Low, "v", "ʋ"),
+    ('ຫ', ConsonantClass::High, "h", "h"),
+    ('ອ', ConsonantClass::Middle, "", "ʔ"),
+    ('ຮ', ConsonantClass::Low, "h", "h"),
+];
+
+/// Consonants which can end a syllable with their romanization and their IPA
+const FINALS: [(char, &str, &str); 8] = [
+    ('ກ', "k", "k̚"),
+    ('ງ', "ng", "ŋ"),
+    ('ດ', "t", "t̚"),
+    ('ນ', "n", "n"),
+    ('ບ', "p", "p̚"),
+    ('ມ', "m", "m"),
+    ('ຍ', "y", "j"),
+    ('ວ', "o", "w"),
+];
+
+/// Consonants which are read as a high class consonant when they're preceded by ຫ
+const HO_DIGRAPHS: [char; 6] = ['ງ', 'ຍ', 'ນ', 'ມ', 'ລ', 'ວ'];
+
+/// Signs which are always attached to a consonant. A consonant followed by one of them starts a new syllable
+const DEPENDENT_SIGNS: [char; 14] = ['ະ', 'ັ', 'າ', 'ຳ', 'ິ', 'ີ', 'ຶ', 'ື', 'ຸ', 'ູ', 'ົ', 'ຼ', 'ຽ', 'ໍ'];
+
+/// The cancellation mark which silences a consonant in loanwords
+const CANCELLATION_MARK: char = '໌';
+
+/// Initial consonant (or consonant cluster) of a syllable
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Initial {
+    /// Letters of the initial e.g: "ຫຍ"
+    pub letters: String,
+    pub class: ConsonantClass,
+    pub romanization: String,
+    pub ipa: String,
+}
+
+/// A lao syllable
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Syllable {
+    /// Text of the syllable in its canonical spelling
+    pub text: String,
+    pub initial: Initial,
+    pub vowel: &'static Vowel,
+    pub final_consonant: Option<char>,
+    pub tone_mark: Option<ToneMark>,
+}
+
+/// A text is split in syllables. Characters which are not part of a syllable (digits, punctuations, latin...)
+/// are kept in Other
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    Syllable(Syllable),
+    Other(String),
+}
+
+impl Syllable {
+    /// Get the class of the initial consonant
+    pub fn class(&self) -> ConsonantClass {
+        self.initial.class
+    }
+
+    /// Get whether the syllable is live or dead
+    pub fn syllable_type(&self) -> SyllableType {
+        match self.final_consonant {
+            Some('ກ' | 'ດ' | 'ບ') => SyllableType::Dead,
+            Some(_) => SyllableType::Live,
+            None if self.vowel.length == VowelLength::Short && !self.vowel.glide => {
+                SyllableType::Dead
+            }
+            None => SyllableType::Live,
+        }
+    }
+
+    /// Compute the tone of the syllable from the class of the initial consonant, the type of the syllable,
+    /// the length of the vowel and the tone mark
+    pub fn tone(&self) -> Tone {
+        let class = self.class();
+        match self.tone_mark {
+            Some(ToneMark::MaiEk) => Tone::Mid,
+            Some(ToneMark::MaiTho) if class == ConsonantClass::High => Tone::LowFalling,
+            Some(ToneMark::MaiTho) => Tone::HighFalling,
+            Some(ToneMark::MaiTi) => Tone::High,
+            Some(ToneMark::MaiChattawa) => Tone::Rising,
+            None => match (self.syllable_type(), self.vowel.length, class) {
+                (SyllableType::Live, _, ConsonantClass::High) => Tone::Rising,
+                (SyllableType::Live, _, ConsonantClass::Middle) => Tone::LowRising,
+                (SyllableType::Live, _, ConsonantClass::Low) => Tone::High,
+                (SyllableType::Dead, VowelLength::Short, ConsonantClass::Low) => Tone::Mid,
+                (SyllableType::Dead, VowelLength::Short, _) => Tone::High,
+                (SyllableType::Dead, VowelLength::Long, ConsonantClass::Low) => Tone::HighFalling,
+                (SyllableType::Dead, VowelLength::Long, _) => Tone::LowFalling,
+            },
+        }
+    }
+}
+
+/// Split a lao text into syllables. The text is normalized before being split
+///
+/// # Arguments
+///
+/// * `text` - &str
+pub fn split_into_syllables(text: &str) -> Vec<Segment> {
+    let chars = normalize::normalize(text).chars().collect::<Vec<_>>();
+    let mut segments = Vec::new();
+    let mut pos = 0;
+
+    while pos < chars.len() {
+        // the longest match is used. When two vowels match the same length, the first one of the list is used
+        let best = VOWELS
+            .iter()
+            .filter_map(|vowel| match_vowel(&chars, pos, vowel))
+            .fold(None, |best: Option<Syllable>, syllable| match best {
+                Some(b) if b.text.chars().count() >= syllable.text.chars().count() => Some(b),
+                _ => Some(syllable),
+            });
+
+        match best {
+            Some(syllable) => {
+                pos += syllable.text.chars().count();
+                segments.push(Segment::Syllable(syllable));
+            }
+            None => {
+                match segments.last_mut() {
+                    Some(Segment::Other(other)) => other.push(chars[pos]),
+                    _ => segments.push(Segment::Other(chars[pos].to_string())),
+                }
+                pos += 1;
+            }
+        }
+    }
+
+    segments
+}
+
+/// Try to read a syllable written with the given vowel at the position
+///
+/// # Arguments
+///
+/// * `chars` - &[char]
+/// * `start` - usize
+/// * `vowel` - &'static Vowel
+fn match_vowel(chars: &[char], start: usize, vowel: &'static Vowel) -> Option<Syllable> {
+    let mut pos = start;
+    let mut initial = None;
+    let mut final_consonant = None;
+    let mut tone_mark = None;
+
+    for p in vowel.pattern.chars() {
+        if initial.is_some() {
+            skip_marks(chars, &mut pos, &mut tone_mark);
+        }
+
+        match p {
+            'C' => {
+                let (i, len) = parse_initial(chars, pos)?;
+                initial = Some(i);
+                pos += len;
+            }
+            'F' | 'f' => match chars.get(pos) {
+                Some(c) if is_final(chars, pos) => {
+                    final_consonant = Some(*c);
+                    pos += 1;
+                }
+                _ if p == 'F' => return None,
+                _ => {}
+            },
+            literal => {
+                if chars.get(pos) != Some(&literal) {
+                    return None;
+                }
+                pos += 1;
+            }
+        }
+    }
+
+    skip_marks(chars, &mut pos, &mut tone_mark);
+
+    Some(Syllable {
+        text: chars[start..pos].iter().collect(),
+        initial: initial?,
+        vowel,
+        final_consonant,
+        tone_mark,
+    })
+}
+
+/// Move the position after the tone marks and the cancellation marks
+///
+/// # Arguments
+///
+/// * `chars` - &[char]
+/// * `pos` - &mut usize
+/// * `tone_mark` - &mut Option<ToneMark>
+fn skip_marks(chars: &[char], pos: &mut usize, tone_mark: &mut Option<ToneMark>) {
+    while let Some(c) = chars.get(*pos) {
+        match to_tone_mark(*c) {
+            Some(mark) => *tone_mark = Some(mark),
+            None if *c == CANCELLATION_MARK => {}
+            None => break,
+        }
+        *pos += 1;
+    }
+}
+
+/// Parse the initial consonant at the position. Return the initial and the number of characters used
+///
+/// # Arguments
+///
+/// * `chars` - &[char]
+/// * `pos` - usize
+fn parse_initial(chars: &[char], pos: usize) -> Option<(Initial, usize)> {
+    let (letter, class, romanization, ipa) = consonant(*chars.get(pos)?)?;
+    let mut initial = Initial {
+        letters: letter.to_string(),
+        class,
+        romanization: romanization.to_string(),
+        ipa: ipa.to_string(),
+    };
+
+    // ຫ followed by a sonorant is a single high class consonant e.g: ຫຍ, ຫນ, ຫລ
+    let mut len = 1;
+    if let Some(next) = chars
+        .get(pos + 1)
+        .filter(|c| letter == 'ຫ' && HO_DIGRAPHS.contains(c))
+    {
+        let (_, _, romanization, ipa) = consonant(*next)?;
+        initial.letters.push(*next);
+        initial.romanization = romanization.to_string();
+        initial.ipa = ipa.to_string();
+        len += 1;
+    }
+
+    // ວ following a consonant and followed by a vowel is a medial e.g: ຄວາມ, ກວ່າ
+    let last = initial.letters.chars().last()?;
+    if chars.get(pos + len) == Some(&'ວ') && !matches!(last, 'ອ' | 'ວ') {
+        let after = chars[pos + len + 1..]
+            .iter()
+            .find(|c| to_tone_mark(**c).is_none());
+
+        if after.is_some_and(|c| matches!(c, 'ະ' | 'ັ' | 'າ' | 'ຳ' | 'ິ' | 'ີ')) {
+            initial.letters.push('ວ');
+            initial.romanization.push_str("ou");
+            initial.ipa.push('w');
+            len += 1;
+        }
+    }
+
+    Some((initial, len))
+}
+
+/// Check whether the consonant at the position ends the current syllable rather than starting a new one
+///
+/// # Arguments
+///
+/// * `chars` - &[char]
+/// * `pos` - usize
+fn is_final(chars: &[char], pos: usize) -> bool {
+    let Some(c) = chars.get(pos) else {
+        return false;
+    };
+
+    if !FINALS.iter().any(|(f, _, _)| f == c) {
+        return false;
+    }
+
+    match chars.get(pos + 1) {
+        Some(next) => !DEPENDENT_SIGNS.contains(next) && to_tone_mark(*next).is_none(),
+        None => true,
+    }
+}
+
+/// Get a consonant from the table of consonants
+///
+/// # Arguments
+///
+/// * `c` - char
+fn consonant(c: char) -> Option<(char, ConsonantClass, &'static str, &'static str)> {
+    CONSONANTS.iter().find(|(letter, ..)| *letter == c).copied()
+}
+
+/// Get the position of a consonant in the lao alphabet
+///
+/// # Arguments
+///
+/// * `c` - char
+pub(crate) fn consonant_order(c: char) -> Option<u32> {
+    CONSONANTS
+        .iter()
+        .position(|(letter, ..)| *letter == c)
+        .map(|idx| idx as u32)
+}
+
+/// Get the romanization and the IPA of a final consonant
+///
+/// # Arguments
+///
+/// * `c` - char
+pub(crate) fn final_consonant(c: char) -> Option<(&'static str, &'static str)> {
+    FINALS
+        .iter()
+        .find(|(letter, ..)| *letter == c)
+        .map(|(_, romanization, ipa)| (*romanization, *ipa))
+}
+
+/// Convert a character into a tone mark
+///
+/// # Arguments
+///
+/// * `c` - char
+fn to_tone_mark(c: char) -> Option<ToneMark> {
+    match c {
+        '\u{0EC8}' => Some(ToneMark::MaiEk),
+        '\u{0EC9}' => Some(ToneMark::MaiTho),
+        '\u{0ECA}' => Some(ToneMark::MaiTi),
+        '\u{0ECB}' => Some(ToneMark::MaiChattawa),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn syllables(text: &str) -> Vec<Syllable> {
+        split_into_syllables(text)
+            .into_iter()
+            .filter_map(|segment| match segment {
+                Segment::Syllable(s) => Some(s),
+                Segment::Other(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn expect_to_split_word_into_syllables() {
+        let texts = syllables("ສະບາຍດີ")
+            .into_iter()
+            .map(|s| s.text)
+            .collect::<Vec<_>>();
+        assert_eq!(texts, vec!["ສະ", "ບາຍ", "ດີ"]);
+
+        let texts = syllables("ເຈົ້າໄປໂຮງຮຽນບໍ່")
+            .into_iter()
+            .map(|s| s.text)
+            .collect::<Vec<_>>();
+        assert_eq!(texts, vec!["ເຈົ້າ", "ໄປ", "ໂຮງ", "ຮຽນ", "ບໍ່"]);
+    }
+
+    #[test]
+    fn expect_to_parse_initial_clusters() {
+        let nang = &syllables("ໜັງ")[0];
+        assert_eq!(nang.initial.letters, "ຫນ");
+        assert_eq!(nang.class(), ConsonantClass::High);
+        assert_eq!(nang.final_consonant, Some('ງ'));
+
+        let khuam = &syllables("ຄວາມ")[0];
+        assert_eq!(khuam.initial.letters, "ຄວ");
+        assert_eq!(khuam.final_consonant, Some('ມ'));
+    }
+
+    #[test]
+    fn expect_to_compute_tone() {
+        let kin = &syllables("ກິນ")[0];
+        assert_eq!(kin.class(), ConsonantClass::Middle);
+        assert_eq!(kin.syllable_type(), SyllableType::Live);
+        assert_eq!(kin.tone(), Tone::LowRising);
+
+        let mak = &syllables("ມາກ")[0];
+        assert_eq!(mak.syllable_type(), SyllableType::Dead);
+        assert_eq!(mak.tone(), Tone::HighFalling);
+
+        let dai = &syllables("ໄດ້")[0];
+        assert_eq!(dai.tone_mark, Some(ToneMark::MaiTho));
+        assert_eq!(dai.tone(), Tone::HighFalling);
+    }
+
+    #[test]
+    fn expect_to_read_ro_as_lo() {
+        let ri = &syllables("ຣິ")[0];
+        assert_eq!(ri.initial.letters, "ລ");
+        assert_eq!(ri.initial.romanization, "l");
+        assert_eq!(ri.class(), ConsonantClass::Low);
+    }
+
+    #[test]
+    fn expect_to_keep_other_characters() {
+        let segments = split_into_syllables("ກິນ 3");
+        assert_eq!(segments.last(), Some(&Segment::Other(" 3".to_string())));
+    }
+}
@@ -0,0 +1,142 @@
+use super::Token;
+use super::syllable::{self, Segment, Syllable};
+use crate::dictionary::{Dictionary, Laotian};
+use crate::word::Word;
+
+/// A word of a sentence with its romanization and its IPA
+#[derive(Debug, Clone)]
+pub struct TransliteratedWord<'a> {
+    /// Text of the word as written in the sentence
+    pub text: String,
+    /// Definition of the word. None when the word is not present in the dictionary
+    pub word: Option<&'a Word>,
+    pub romanization: String,
+    pub ipa: String,
+}
+
+impl Syllable {
+    /// Romanize the syllable using the BGN/PCGN system e.g: ສະບາຍ -> sa, bay
+    pub fn romanize(&self) -> String {
+        let final_consonant = self
+            .final_consonant
+            .and_then(syllable::final_consonant)
+            .map(|(romanization, _)| romanization)
+            .unwrap_or_default();
+
+        format!(
+            "{}{}{}",
+            self.initial.romanization, self.vowel.romanization, final_consonant
+        )
+    }
+
+    /// Transcribe the syllable in IPA with its tone e.g: ກິນ -> kin˩˧
+    pub fn to_ipa(&self) -> String {
+        let final_consonant = self
+            .final_consonant
+            .and_then(syllable::final_consonant)
+            .map(|(_, ipa)| ipa)
+            .unwrap_or_default();
+
+        format!(
+            "{}{}{}{}",
+            self.initial.ipa,
+            self.vowel.ipa,
+            final_consonant,
+            self.tone().ipa()
+        )
+    }
+}
+
+/// Romanize a lao text using the BGN/PCGN system. The syllables of the text are joined together and
+/// the characters which are not lao are kept as is.
+///
+/// # Arguments
+///
+/// * `text` - &str
+pub fn romanize(text: &str) -> String {
+    syllable::split_into_syllables(text)
+        .iter()
+        .map(|segment| match segment {
+            Segment::Syllable(s) => s.romanize(),
+            Segment::Other(other) => other.clone(),
+        })
+        .collect()
+}
+
+/// Transcribe a lao text in IPA. Syllables are separated by a dot and the characters which are not lao
+/// are kept as is.
+///
+/// # Arguments
+///
+/// * `text` - &str
+pub fn to_ipa(text: &str) -> String {
+    let mut ipa = String::new();
+    let mut previous_is_syllable = false;
+
+    for segment in syllable::split_into_syllables(text) {
+        match segment {
+            Segment::Syllable(s) => {
+                if previous_is_syllable {
+                    ipa.push('.');
+                }
+                ipa.push_str(&s.to_ipa());
+                previous_is_syllable = true;
+            }
+            Segment::Other(other) => {
+                ipa.push_str(&other);
+                previous_is_syllable = false;
+            }
+        }
+    }
+
+    ipa
+}
+
+impl Dictionary<Laotian> {
+    /// Segment a sentence into words and transliterate each of them. Words which are not present
+    /// in the dictionary are transliterated too.
+    ///
+    /// # Arguments
+    ///
+    /// * `sentence` - S
+    pub fn transliterate<S: AsRef<str>>(&self, sentence: S) -> Vec<TransliteratedWord<'_>> {
+        self.segment(sentence.as_ref())
+            .into_iter()
+            .filter(|token| !token.text().trim().is_empty())
+            .map(|token| {
+                let (text, word) = match token {
+                    Token::Word(text, word) => (text, word),
+                    Token::Numeral(text, _) => (text, None),
+                };
+
+                TransliteratedWord {
+                    romanization: romanize(&text),
+                    ipa: to_ipa(&text),
+                    text,
+                    word,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_romanize_text() {
+        assert_eq!(romanize("ສະບາຍດີ"), "sabaydi");
+        assert_eq!(romanize("ລາວ"), "lao");
+        assert_eq!(romanize("ຫຍັງ"), "gnang");
+        assert_eq!(romanize("ເມືອງ 3"), "muang 3");
+        assert_eq!(romanize("ອາເມຣິກາ"), "amélika");
+    }
+
+    #[test]
+    fn expect_to_transcribe_text_in_ipa() {
+        assert_eq!(to_ipa("ກິນ"), "kin˩˧");
+        assert_eq!(to_ipa("ມາກ"), "maːk̚˥˧");
+        assert_eq!(to_ipa("ສະບາຍ"), "sa˥.baːj˩˧");
+    }
+}
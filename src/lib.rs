@@ -1,14 +1,25 @@
 #[cfg(feature = "chinese")]
 pub mod chinese;
 
+pub mod anki;
+pub mod collation;
 pub mod dictionary;
 pub mod error;
 pub mod export;
+pub mod html;
+pub mod import;
+pub mod interlinear;
+pub mod known;
 
 #[cfg(feature = "laotian")]
 pub mod laotian;
 
+pub mod multi;
 mod punctuation;
+pub mod registry;
+pub mod script;
+pub mod search;
+pub mod srs;
 pub(crate) mod util;
 pub mod word;
 
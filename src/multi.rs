@@ -0,0 +1,166 @@
+#[cfg(feature = "chinese")]
+use crate::dictionary::Chinese;
+#[cfg(any(feature = "chinese", feature = "laotian"))]
+use crate::dictionary::Dictionary;
+#[cfg(feature = "laotian")]
+use crate::dictionary::Laotian;
+use crate::dictionary::{Language, LanguageDictionary};
+use crate::script::{self, Script};
+use crate::word::{Word, WordParserResult};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Type alias to BTreeMap<String, TaggedWord>
+pub type MultiWordParserResult = BTreeMap<String, TaggedWord>;
+
+/// A word which has been found in one of the dictionaries of a MultiDictionary
+#[derive(Clone, Debug, Serialize)]
+pub struct TaggedWord {
+    pub language: Language,
+    pub word: Word,
+}
+
+/// MultiDictionary detects the script of each run of a sentence and dispatch
+/// the run to the dictionary which support the detected script.
+/// Runs which does not have a matching loaded dictionary are ignored.
+#[derive(Default)]
+pub struct MultiDictionary {
+    #[cfg(feature = "chinese")]
+    pub chinese: Option<Dictionary<Chinese>>,
+    #[cfg(feature = "laotian")]
+    pub laotian: Option<Dictionary<Laotian>>,
+}
+
+impl MultiDictionary {
+    /// Create an empty MultiDictionary
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the dictionary used to parse the Han script
+    ///
+    /// # Arguments
+    ///
+    /// * `dictionary` - Dictionary<Chinese>
+    #[cfg(feature = "chinese")]
+    pub fn with_chinese(mut self, dictionary: Dictionary<Chinese>) -> Self {
+        self.chinese = Some(dictionary);
+        self
+    }
+
+    /// Set the dictionary used to parse the Lao script
+    ///
+    /// # Arguments
+    ///
+    /// * `dictionary` - Dictionary<Laotian>
+    #[cfg(feature = "laotian")]
+    pub fn with_laotian(mut self, dictionary: Dictionary<Laotian>) -> Self {
+        self.laotian = Some(dictionary);
+        self
+    }
+
+    /// Parse a sentence which may contains multiple languages. Each word is tagged with the language
+    /// of the dictionary which has found it.
+    ///
+    /// # Arguments
+    ///
+    /// * `sentence` - S
+    pub fn parse_sentence_into_words<S: AsRef<str>>(&self, sentence: S) -> MultiWordParserResult {
+        let mut words = BTreeMap::new();
+
+        for run in script::split_into_runs(sentence.as_ref()) {
+            if let Some((language, res)) = self.parse_run(run.script, run.text) {
+                merge(&mut words, language, res);
+            }
+        }
+
+        words
+    }
+
+    /// Parse a run with the dictionary matching the script of the run
+    ///
+    /// # Arguments
+    ///
+    /// * `script` - Script
+    /// * `text` - &str
+    fn parse_run(&self, script: Script, text: &str) -> Option<(Language, WordParserResult)> {
+        let dictionary: Option<(Language, &dyn LanguageDictionary)> = match script {
+            #[cfg(feature = "chinese")]
+            Script::Han => self
+                .chinese
+                .as_ref()
+                .map(|dict| (Language::Chinese, dict as &dyn LanguageDictionary)),
+            #[cfg(feature = "laotian")]
+            Script::Lao => self
+                .laotian
+                .as_ref()
+                .map(|dict| (Language::Laotian, dict as &dyn LanguageDictionary)),
+            _ => None,
+        };
+
+        dictionary.map(|(language, dict)| (language, dict.parse(text)))
+    }
+}
+
+/// Merge the result of a run into the global result. The counter of a word
+/// which has already been found in a previous run is increased.
+///
+/// # Arguments
+///
+/// * `words` - &mut MultiWordParserResult
+/// * `language` - Language
+/// * `res` - WordParserResult
+fn merge(words: &mut MultiWordParserResult, language: Language, res: WordParserResult) {
+    for (key, word) in res {
+        match words.get_mut(&key) {
+            Some(tagged) => tagged.word.count += word.count,
+            None => {
+                words.insert(key, TaggedWord { language, word });
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "chinese", feature = "laotian"))]
+mod tests {
+    use super::*;
+    use crate::KeyVariant;
+    use crate::dictionary::Lang;
+    use std::path::PathBuf;
+    use std::sync::LazyLock;
+
+    static DICTIONARY: LazyLock<MultiDictionary> = LazyLock::new(|| {
+        let chinese = crate::load_chinese_dictionary(
+            Lang::Chinese(KeyVariant::Traditional),
+            PathBuf::from("./cedict_ts.u8"),
+        )
+        .unwrap();
+        let laotian =
+            crate::load_laotian_dictionary(PathBuf::from("./lao-eng-dictionary.csv")).unwrap();
+
+        MultiDictionary::new()
+            .with_chinese(chinese)
+            .with_laotian(laotian)
+    });
+
+    #[test]
+    fn expect_to_parse_mixed_sentence() {
+        let words = DICTIONARY.parse_sentence_into_words("你好, ລູກຫລ້າຢາກໄດ້ກິນຫຍັງ? hello 你好");
+
+        let nihao = words.get("你好").unwrap();
+        assert_eq!(nihao.language, Language::Chinese);
+        assert_eq!(nihao.word.count, 2);
+
+        let baby = words.get("ລູກຫລ້າ").unwrap();
+        assert_eq!(baby.language, Language::Laotian);
+        assert_eq!(baby.word.count, 1);
+    }
+
+    #[test]
+    fn expect_to_ignore_run_without_dictionary() {
+        let multi = MultiDictionary::new();
+        let words = multi.parse_sentence_into_words("你好 ກິນ");
+
+        assert!(words.is_empty());
+    }
+}
@@ -0,0 +1,238 @@
+#[cfg(feature = "chinese")]
+use crate::dictionary::Chinese;
+#[cfg(feature = "laotian")]
+use crate::dictionary::Laotian;
+#[cfg(any(feature = "chinese", feature = "laotian"))]
+use crate::dictionary::{Dictionary, Initializer};
+use crate::dictionary::{Lang, Language, LanguageDictionary};
+use crate::error::DictionaryError;
+use dodo_zh::variant::KeyVariant;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Type alias to a type erased dictionary
+pub type AnyDictionary = Box<dyn LanguageDictionary>;
+
+/// BCP-47 language tags supported by the library
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LanguageTag {
+    /// zh-Hant
+    ChineseTraditional,
+    /// zh-Hans
+    ChineseSimplified,
+    /// lo
+    Lao,
+}
+
+impl LanguageTag {
+    /// Get the canonical BCP-47 representation of the tag
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LanguageTag::ChineseTraditional => "zh-Hant",
+            LanguageTag::ChineseSimplified => "zh-Hans",
+            LanguageTag::Lao => "lo",
+        }
+    }
+
+    /// Get the language of the tag
+    pub fn language(&self) -> Language {
+        match self {
+            LanguageTag::ChineseTraditional | LanguageTag::ChineseSimplified => Language::Chinese,
+            LanguageTag::Lao => Language::Laotian,
+        }
+    }
+
+    /// Get the parameters used to initialize a dictionary for the tag
+    pub fn lang(&self) -> Lang {
+        match self {
+            LanguageTag::ChineseTraditional => Lang::Chinese(KeyVariant::Traditional),
+            LanguageTag::ChineseSimplified => Lang::Chinese(KeyVariant::Simplified),
+            LanguageTag::Lao => Lang::Laotian(None),
+        }
+    }
+}
+
+impl FromStr for LanguageTag {
+    type Err = DictionaryError;
+
+    /// Parse a BCP-47 tag. Region subtags are mapped to the script used in the region (e.g: zh-TW -> zh-Hant)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let subtags = s
+            .split(['-', '_'])
+            .map(|subtag| subtag.to_ascii_lowercase())
+            .collect::<Vec<_>>();
+
+        let subtags = subtags.iter().map(String::as_str).collect::<Vec<_>>();
+        match subtags.as_slice() {
+            ["zh", "hant", ..] | ["zh", "tw" | "hk" | "mo"] => Ok(LanguageTag::ChineseTraditional),
+            ["zh", "hans", ..] | ["zh", "cn" | "sg"] => Ok(LanguageTag::ChineseSimplified),
+            ["lo"] | ["lo", "laoo" | "la", ..] => Ok(LanguageTag::Lao),
+            _ => Err(DictionaryError::Language(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for LanguageTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A registry of dictionaries which can be addressed at runtime by their BCP-47 tag
+#[derive(Default)]
+pub struct DictionaryRegistry {
+    dictionaries: BTreeMap<LanguageTag, AnyDictionary>,
+}
+
+impl DictionaryRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a dictionary for the given tag and register it. A dictionary previously registered
+    /// for the same tag is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - &str
+    /// * `path` - PathBuf
+    pub fn load(&mut self, tag: &str, path: PathBuf) -> Result<(), DictionaryError> {
+        let tag = LanguageTag::from_str(tag)?;
+        let dictionary = load_dictionary(tag, path)?;
+
+        self.dictionaries.insert(tag, dictionary);
+
+        Ok(())
+    }
+
+    /// Register a dictionary which has already been loaded
+    ///
+    /// # Arguments
+    ///
+    /// * `dictionary` - AnyDictionary
+    pub fn insert(&mut self, dictionary: AnyDictionary) {
+        self.dictionaries.insert(dictionary.tag(), dictionary);
+    }
+
+    /// Get the dictionary registered for the given tag
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - &str
+    pub fn get(&self, tag: &str) -> Option<&dyn LanguageDictionary> {
+        let tag = LanguageTag::from_str(tag).ok()?;
+
+        self.dictionaries.get(&tag).map(|dict| dict.as_ref())
+    }
+
+    /// Remove the dictionary registered for the given tag
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - &str
+    pub fn remove(&mut self, tag: &str) -> Option<AnyDictionary> {
+        let tag = LanguageTag::from_str(tag).ok()?;
+
+        self.dictionaries.remove(&tag)
+    }
+
+    /// List the tags of the registered dictionaries in the order of the LanguageTag enum
+    pub fn tags(&self) -> Vec<LanguageTag> {
+        self.dictionaries.keys().copied().collect()
+    }
+}
+
+/// Load a type erased dictionary for the given tag
+///
+/// # Arguments
+///
+/// * `tag` - LanguageTag
+/// * `path` - PathBuf
+fn load_dictionary(tag: LanguageTag, path: PathBuf) -> Result<AnyDictionary, DictionaryError> {
+    // the path is only read by the dictionaries of the enabled features
+    #[cfg(not(any(feature = "chinese", feature = "laotian")))]
+    let _ = path;
+
+    match tag.language() {
+        #[cfg(feature = "chinese")]
+        Language::Chinese => {
+            let mut dictionary = Dictionary::<Chinese>::initialize(tag.lang())?;
+            dictionary.load(path)?;
+
+            Ok(Box::new(dictionary))
+        }
+        #[cfg(feature = "laotian")]
+        Language::Laotian => {
+            let mut dictionary = Dictionary::<Laotian>::initialize(tag.lang())?;
+            dictionary.load(path)?;
+
+            Ok(Box::new(dictionary))
+        }
+        #[cfg(not(all(feature = "chinese", feature = "laotian")))]
+        _ => Err(DictionaryError::Language(format!(
+            "{tag} is not enabled by the features of the crate"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_parse_language_tags() {
+        assert_eq!(
+            LanguageTag::from_str("zh-Hant").unwrap(),
+            LanguageTag::ChineseTraditional
+        );
+        assert_eq!(
+            LanguageTag::from_str("zh-TW").unwrap(),
+            LanguageTag::ChineseTraditional
+        );
+        assert_eq!(
+            LanguageTag::from_str("zh_hans_CN").unwrap(),
+            LanguageTag::ChineseSimplified
+        );
+        assert_eq!(LanguageTag::from_str("lo").unwrap(), LanguageTag::Lao);
+        assert!(LanguageTag::from_str("zh").is_err());
+        assert!(LanguageTag::from_str("en").is_err());
+    }
+
+    #[cfg(feature = "laotian")]
+    #[test]
+    fn expect_to_load_and_get_dictionary_by_tag() {
+        let mut registry = DictionaryRegistry::new();
+        registry
+            .load("lo", PathBuf::from("./lao-eng-dictionary.csv"))
+            .unwrap();
+
+        let dictionary = registry.get("lo-LA").unwrap();
+        assert_eq!(dictionary.tag(), LanguageTag::Lao);
+        assert_eq!(dictionary.lookup("ຮັກ").unwrap().translations, vec!["love"]);
+
+        let words = dictionary.parse("ລູກຫລ້າຢາກໄດ້ກິນຫຍັງ");
+        assert!(words.contains_key("ລູກຫລ້າ"));
+
+        assert!(registry.get("zh-Hant").is_none());
+    }
+
+    #[cfg(all(feature = "chinese", feature = "laotian"))]
+    #[test]
+    fn expect_to_list_tags_in_a_stable_order() {
+        let mut registry = DictionaryRegistry::new();
+        registry
+            .load("lo", PathBuf::from("./lao-eng-dictionary.csv"))
+            .unwrap();
+        registry
+            .load("zh-Hant", PathBuf::from("./cedict_ts.u8"))
+            .unwrap();
+
+        assert_eq!(
+            registry.tags(),
+            vec![LanguageTag::ChineseTraditional, LanguageTag::Lao]
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use serde::Serialize;
+
+/// Subset of the Unicode Script property which is relevant for the supported dictionaries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum Script {
+    Han,
+    Lao,
+    Thai,
+    Latin,
+    /// Characters shared by multiple scripts such as digits, punctuations or whitespaces
+    Common,
+    Unknown,
+}
+
+/// A run is a slice of a text where every character belongs to the same script.
+/// Common characters are attached to the run which surround them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptRun<'a> {
+    pub script: Script,
+    pub text: &'a str,
+}
+
+impl Script {
+    /// Get the script of a character based on the unicode block it belongs to. The blocks are hard-coded
+    /// (Unicode 15) instead of using the Script property, so characters added to new blocks are Unknown
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - char
+    pub fn of(c: char) -> Script {
+        match c as u32 {
+            // CJK radicals, CJK Unified Ideographs & extensions, compatibility ideographs
+            0x2E80..=0x2FDF
+            | 0x3005
+            | 0x3007
+            | 0x3021..=0x3029
+            | 0x3038..=0x303B
+            | 0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xF900..=0xFAFF
+            | 0x20000..=0x2FA1F
+            | 0x30000..=0x323AF => Script::Han,
+            0x0E01..=0x0E3A | 0x0E40..=0x0E5B => Script::Thai,
+            0x0E81..=0x0EFF => Script::Lao,
+            0x0041..=0x005A
+            | 0x0061..=0x007A
+            | 0x00AA
+            | 0x00BA
+            | 0x00C0..=0x00D6
+            | 0x00D8..=0x00F6
+            | 0x00F8..=0x024F
+            | 0x1E00..=0x1EFF
+            | 0xFF21..=0xFF3A
+            | 0xFF41..=0xFF5A => Script::Latin,
+            _ if c.is_whitespace() || c.is_ascii() || is_common_punctuation(c) => Script::Common,
+            _ => Script::Unknown,
+        }
+    }
+}
+
+/// Split a text into runs of the same script. Common characters (punctuations, digits, whitespaces)
+/// does not break a run. They're appended to the current run or to the next one if the text starts with them.
+///
+/// # Arguments
+///
+/// * `text` - &str
+pub fn split_into_runs(text: &str) -> Vec<ScriptRun<'_>> {
+    let mut runs = Vec::new();
+    let mut current: Option<Script> = None;
+    let mut start = 0;
+
+    for (idx, c) in text.char_indices() {
+        let script = Script::of(c);
+        if script == Script::Common {
+            continue;
+        }
+
+        match current {
+            Some(s) if s != script => {
+                runs.push(ScriptRun {
+                    script: s,
+                    text: &text[start..idx],
+                });
+                start = idx;
+                current = Some(script);
+            }
+            None => current = Some(script),
+            _ => {}
+        }
+    }
+
+    if start < text.len() {
+        runs.push(ScriptRun {
+            script: current.unwrap_or(Script::Common),
+            text: &text[start..],
+        });
+    }
+
+    runs
+}
+
+/// Check whether the character is a punctuation shared by the CJK and the general punctuation blocks
+///
+/// # Arguments
+///
+/// * `c` - char
+fn is_common_punctuation(c: char) -> bool {
+    matches!(c as u32,
+        0x00A0..=0x00BF
+        | 0x00D7
+        | 0x00F7
+        | 0x2000..=0x206F
+        | 0x3000..=0x3004
+        | 0x3008..=0x3020
+        | 0x30FB
+        | 0xFF01..=0xFF20
+        | 0xFF3B..=0xFF40
+        | 0xFF5B..=0xFF65
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_detect_script_of_characters() {
+        assert_eq!(Script::of('你'), Script::Han);
+        assert_eq!(Script::of('ກ'), Script::Lao);
+        assert_eq!(Script::of('ก'), Script::Thai);
+        assert_eq!(Script::of('é'), Script::Latin);
+        assert_eq!(Script::of('。'), Script::Common);
+        assert_eq!(Script::of('3'), Script::Common);
+    }
+
+    #[test]
+    fn expect_to_detect_script_at_block_edges() {
+        // CJK Extension B
+        assert_eq!(Script::of('\u{20000}'), Script::Han);
+        assert_eq!(Script::of('\u{2A6DF}'), Script::Han);
+        // CJK compatibility ideographs
+        assert_eq!(Script::of('\u{F900}'), Script::Han);
+        assert_eq!(Script::of('\u{FAFF}'), Script::Han);
+        assert_eq!(Script::of('\u{2F800}'), Script::Han);
+        // Lao digits
+        assert_eq!(Script::of('໐'), Script::Lao);
+        assert_eq!(Script::of('໙'), Script::Lao);
+        assert_eq!(Script::of('\u{0E80}'), Script::Unknown);
+        // Thai digits
+        assert_eq!(Script::of('๐'), Script::Thai);
+        assert_eq!(Script::of('\u{0E5C}'), Script::Unknown);
+    }
+
+    #[test]
+    fn expect_to_split_text_into_runs() {
+        let runs = split_into_runs("我愛ລາວ. hello 你好");
+
+        assert_eq!(
+            runs,
+            vec![
+                ScriptRun {
+                    script: Script::Han,
+                    text: "我愛"
+                },
+                ScriptRun {
+                    script: Script::Lao,
+                    text: "ລາວ. "
+                },
+                ScriptRun {
+                    script: Script::Latin,
+                    text: "hello "
+                },
+                ScriptRun {
+                    script: Script::Han,
+                    text: "你好"
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn expect_leading_common_characters_to_join_first_run() {
+        let runs = split_into_runs(" 1. ກິນ");
+
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].script, Script::Lao);
+        assert_eq!(runs[0].text, " 1. ກິນ");
+    }
+}
@@ -0,0 +1,221 @@
+use crate::dictionary::{Dictionary, LanguageData};
+use crate::word::Word;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+pub mod stemmer;
+
+/// Score given to a sense which is equal to the query
+const WHOLE_SENSE_SCORE: f64 = 2.0;
+
+/// A sense of a word which has been indexed
+struct IndexedSense<'a> {
+    key: &'a str,
+    word: &'a Word,
+    position: usize,
+    keywords: Vec<String>,
+}
+
+/// A word which has been found by a reverse lookup
+#[derive(Debug, Clone)]
+pub struct EnglishMatch<'a> {
+    /// Key of the word in the dictionary
+    pub key: &'a str,
+    pub word: &'a Word,
+    /// Translation which has matched the query
+    pub sense: &'a str,
+    /// Position of the sense in the translations of the word
+    pub position: usize,
+    /// Score of the match. A whole sense match has a score of 2.0. A partial match is
+    /// scored between 0 and 1 depending on the ratio of matched keywords
+    pub score: f64,
+}
+
+/// Inverted index over the translations of a dictionary allowing to search a word by its
+/// english definition e.g: "eat" -> 吃
+pub struct EnglishIndex<'a> {
+    senses: Vec<IndexedSense<'a>>,
+    postings: HashMap<String, Vec<usize>>,
+}
+
+impl<'a> EnglishIndex<'a> {
+    /// Build an index over the translations of the given words
+    ///
+    /// # Arguments
+    ///
+    /// * `dict` - &HashMap<String, Word>
+    pub fn new(dict: &'a HashMap<String, Word>) -> Self {
+        let mut senses = Vec::new();
+        let mut postings: HashMap<String, Vec<usize>> = HashMap::new();
+
+        for (key, word) in dict {
+            for (position, translation) in word.translations.iter().enumerate() {
+                let keywords = stemmer::tokenize(translation);
+                if keywords.is_empty() {
+                    continue;
+                }
+
+                let idx = senses.len();
+                for keyword in keywords.iter().collect::<HashSet<_>>() {
+                    postings.entry(keyword.clone()).or_default().push(idx);
+                }
+
+                senses.push(IndexedSense {
+                    key,
+                    word,
+                    position,
+                    keywords,
+                });
+            }
+        }
+
+        EnglishIndex { senses, postings }
+    }
+
+    /// Search the words which translations match the english query. Results are ranked by score.
+    /// Words which has a sense equal to the query are ranked first, then words which senses
+    /// contains the query. A partial match is scored with the share of the query it matches and the share
+    /// of the sense covered by the query, so a shorter sense ranks higher. Matches with the same score are
+    /// ordered by the position of the sense in the translations, then by the length of the word.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - &str
+    pub fn search_english(&self, query: &str) -> Vec<EnglishMatch<'a>> {
+        let keywords = stemmer::tokenize(query).into_iter().collect::<HashSet<_>>();
+
+        if keywords.is_empty() {
+            return Vec::new();
+        }
+
+        // count the number of keywords of the query which are matched by each sense
+        let mut matched: HashMap<usize, usize> = HashMap::new();
+        for keyword in &keywords {
+            for idx in self.postings.get(keyword).into_iter().flatten() {
+                *matched.entry(*idx).or_default() += 1;
+            }
+        }
+
+        // keep the best sense for each word
+        let mut best: HashMap<&str, (f64, &IndexedSense<'a>)> = HashMap::new();
+        for (idx, count) in matched {
+            let sense = &self.senses[idx];
+            let score = score(keywords.len(), count, sense);
+
+            match best.get(sense.key) {
+                Some((s, b)) if *s > score || (*s == score && b.position <= sense.position) => {}
+                _ => {
+                    best.insert(sense.key, (score, sense));
+                }
+            }
+        }
+
+        let mut results = best
+            .into_values()
+            .map(|(score, sense)| EnglishMatch {
+                key: sense.key,
+                word: sense.word,
+                sense: &sense.word.translations[sense.position],
+                position: sense.position,
+                score,
+            })
+            .collect::<Vec<_>>();
+
+        results.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a.position.cmp(&b.position))
+                .then_with(|| a.key.chars().count().cmp(&b.key.chars().count()))
+                .then_with(|| a.key.cmp(b.key))
+        });
+
+        results
+    }
+}
+
+/// Score a sense for a query
+///
+/// # Arguments
+///
+/// * `query_len` - usize
+/// * `matched` - usize
+/// * `sense` - &IndexedSense
+fn score(query_len: usize, matched: usize, sense: &IndexedSense) -> f64 {
+    let distinct = sense.keywords.iter().collect::<HashSet<_>>().len();
+    if matched == query_len && distinct == query_len {
+        return WHOLE_SENSE_SCORE;
+    }
+
+    // ratio of the query which has been matched weighted by the ratio of the sense covered by the query
+    (matched as f64 / query_len as f64) * (matched as f64 / distinct as f64)
+}
+
+impl<T: LanguageData> Dictionary<T> {
+    /// Build an inverted index over the translations of the dictionary
+    pub fn english_index(&self) -> EnglishIndex<'_> {
+        EnglishIndex::new(&self.dict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(translations: &[&str]) -> Word {
+        Word {
+            translations: translations.iter().map(|t| t.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn expect_whole_sense_match_to_be_ranked_first() {
+        let dict = HashMap::from([
+            ("吃".to_string(), word(&["to eat", "to consume"])),
+            (
+                "吃飯".to_string(),
+                word(&["to have a meal", "to eat", "to make a living"]),
+            ),
+            (
+                "吃苦".to_string(),
+                word(&["to bear hardships", "to suffer"]),
+            ),
+            (
+                "好吃".to_string(),
+                word(&["tasty", "delicious", "good to eat (food)"]),
+            ),
+        ]);
+
+        let index = EnglishIndex::new(&dict);
+        let results = index.search_english("eating");
+
+        let keys = results.iter().map(|r| r.key).collect::<Vec<_>>();
+        assert_eq!(keys, vec!["吃", "吃飯", "好吃"]);
+        assert_eq!(results[0].sense, "to eat");
+        assert_eq!(results[0].score, WHOLE_SENSE_SCORE);
+    }
+
+    #[test]
+    fn expect_to_match_multiple_keywords() {
+        let dict = HashMap::from([
+            ("拉麵".to_string(), word(&["pulled noodles", "ramen"])),
+            ("麵".to_string(), word(&["noodles"])),
+        ]);
+
+        let index = EnglishIndex::new(&dict);
+        let results = index.search_english("pulled noodle");
+
+        assert_eq!(results[0].key, "拉麵");
+        assert_eq!(results[0].score, WHOLE_SENSE_SCORE);
+        assert_eq!(results[1].key, "麵");
+    }
+
+    #[test]
+    fn expect_empty_query_to_return_nothing() {
+        let dict = HashMap::from([("吃".to_string(), word(&["to eat"]))]);
+        let index = EnglishIndex::new(&dict);
+
+        assert!(index.search_english("the").is_empty());
+    }
+}
@@ -0,0 +1,100 @@
+/// Words which does not carry any meaning when searching a definition.
+/// "sb" and "sth" are abbreviations used by cedict for "somebody" and "something"
+const STOP_WORDS: [&str; 12] = [
+    "a", "an", "the", "to", "of", "sb", "sth", "be", "is", "one's", "oneself", "or",
+];
+
+/// Split an english text into stemmed keywords. Stop words are removed
+///
+/// # Arguments
+///
+/// * `text` - &str
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !(c.is_alphanumeric() || c == '\''))
+        .map(|token| token.trim_matches('\''))
+        .filter(|token| !token.is_empty() && !STOP_WORDS.contains(token))
+        .map(stem)
+        .collect()
+}
+
+/// A light english stemmer which remove the plural and the common verb suffixes.
+/// The trailing "e" is always removed so that "make" and "making" share the same stem.
+///
+/// # Arguments
+///
+/// * `word` - &str
+pub fn stem(word: &str) -> String {
+    let mut stem = word.to_string();
+    // avoid stemming short words such as "is", "us", "bus"
+    if stem.chars().count() <= 3 || !stem.is_ascii() {
+        return stem;
+    }
+
+    if let Some(s) = stem.strip_suffix("ies") {
+        stem = format!("{s}y");
+    } else if stem.ends_with("sses") {
+        stem.truncate(stem.len() - 2);
+    } else if stem.ends_with('s') && !stem.ends_with("ss") && !stem.ends_with("us") {
+        stem.pop();
+    }
+
+    for suffix in ["ing", "ed"] {
+        let Some(s) = stem.strip_suffix(suffix) else {
+            continue;
+        };
+
+        if s.len() >= 3 && s.contains(['a', 'e', 'i', 'o', 'u', 'y']) {
+            stem = undouble(s);
+        }
+        break;
+    }
+
+    if stem.len() > 3 && stem.ends_with('e') {
+        stem.pop();
+    }
+
+    stem
+}
+
+/// Remove the doubled consonant left by a suffix e.g: "stopp" -> "stop"
+///
+/// # Arguments
+///
+/// * `s` - &str
+fn undouble(s: &str) -> String {
+    let bytes = s.as_bytes();
+    match bytes {
+        [.., a, b]
+            if a == b && !matches!(a, b'l' | b's' | b'z' | b'a' | b'e' | b'i' | b'o' | b'u') =>
+        {
+            s[..s.len() - 1].to_string()
+        }
+        _ => s.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_stem_words() {
+        assert_eq!(stem("eating"), "eat");
+        assert_eq!(stem("eats"), "eat");
+        assert_eq!(stem("making"), stem("make"));
+        assert_eq!(stem("stopped"), "stop");
+        assert_eq!(stem("loved"), stem("love"));
+        assert_eq!(stem("studies"), "study");
+        assert_eq!(stem("glass"), "glass");
+    }
+
+    #[test]
+    fn expect_to_tokenize_sentence() {
+        assert_eq!(tokenize("to eat (sth)"), vec!["eat"]);
+        assert_eq!(
+            tokenize("Pulled noodles/ramen"),
+            vec!["pull", "noodl", "ramen"]
+        );
+    }
+}
@@ -0,0 +1,322 @@
+use crate::error::DictionaryError;
+use crate::word::{Word, WordParserResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Number of seconds in a day. Intervals are counted in days and timestamps in seconds
+const DAY: u64 = 86_400;
+
+/// Ease of a new card as defined by SM-2
+const INITIAL_EASE: f64 = 2.5;
+
+/// Lowest ease of a card as defined by SM-2
+const MINIMUM_EASE: f64 = 1.3;
+
+/// Quality of the answer of a review as defined by SM-2 (from 0 to 5)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Grade {
+    /// Complete blackout
+    Blackout = 0,
+    /// Incorrect answer but the word was recognized once the answer has been shown
+    Incorrect = 1,
+    /// Incorrect answer but the answer seemed easy to recall
+    Familiar = 2,
+    /// Correct answer recalled with serious difficulty
+    Difficult = 3,
+    /// Correct answer after a hesitation
+    Good = 4,
+    /// Perfect answer
+    Perfect = 5,
+}
+
+impl Grade {
+    /// Get the grade matching a SM-2 quality from 0 to 5
+    ///
+    /// # Arguments
+    ///
+    /// * `quality` - u8
+    pub fn from_quality(quality: u8) -> Option<Self> {
+        match quality {
+            0 => Some(Grade::Blackout),
+            1 => Some(Grade::Incorrect),
+            2 => Some(Grade::Familiar),
+            3 => Some(Grade::Difficult),
+            4 => Some(Grade::Good),
+            5 => Some(Grade::Perfect),
+            _ => None,
+        }
+    }
+
+    /// Whether the answer is considered as correct
+    pub fn is_correct(&self) -> bool {
+        *self >= Grade::Difficult
+    }
+}
+
+/// A flashcard created from a word with its review state. Timestamps are unix timestamps in seconds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Card {
+    /// Key of the word in the dictionary
+    pub key: String,
+    pub written: Vec<String>,
+    pub pronunciations: Vec<String>,
+    pub translations: Vec<String>,
+    /// Number of consecutive correct reviews
+    pub repetitions: u32,
+    /// Number of days between the last review and the next one
+    pub interval: u32,
+    pub ease: f64,
+    /// Number of times the card has been forgotten
+    pub lapses: u32,
+    pub due: u64,
+    pub last_review: Option<u64>,
+}
+
+impl Card {
+    /// Create a new card which is due immediately
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - &str
+    /// * `word` - &Word
+    /// * `now` - u64
+    pub fn new(key: &str, word: &Word, now: u64) -> Self {
+        Card {
+            key: key.to_string(),
+            written: word.written.clone(),
+            pronunciations: word.pronunciations.clone(),
+            translations: word.translations.clone(),
+            repetitions: 0,
+            interval: 0,
+            ease: INITIAL_EASE,
+            lapses: 0,
+            due: now,
+            last_review: None,
+        }
+    }
+
+    /// Update the review state of the card with the SM-2 algorithm
+    ///
+    /// # Arguments
+    ///
+    /// * `grade` - Grade
+    /// * `now` - u64
+    pub fn review(&mut self, grade: Grade, now: u64) {
+        if grade.is_correct() {
+            self.interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (self.interval as f64 * self.ease).round() as u32,
+            };
+            self.repetitions += 1;
+        } else {
+            self.interval = 1;
+            self.repetitions = 0;
+            self.lapses += 1;
+        }
+
+        let distance = 5.0 - grade as u8 as f64;
+        self.ease = (self.ease + 0.1 - distance * (0.08 + distance * 0.02)).max(MINIMUM_EASE);
+        self.due = now + self.interval as u64 * DAY;
+        self.last_review = Some(now);
+    }
+
+    /// Whether the card needs to be reviewed
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - u64
+    pub fn is_due(&self, now: u64) -> bool {
+        self.due <= now
+    }
+}
+
+/// A deck of flashcards. It can be saved to JSON to keep the review state between sessions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Deck {
+    cards: BTreeMap<String, Card>,
+}
+
+impl Deck {
+    /// Create an empty deck
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a deck from its JSON representation
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - &str
+    pub fn from_json(json: &str) -> Result<Self, DictionaryError> {
+        serde_json::from_str(json).map_err(|err| DictionaryError::Serialize(err.to_string()))
+    }
+
+    /// Save the deck to JSON
+    pub fn to_json(&self) -> Result<String, DictionaryError> {
+        serde_json::to_string(self).map_err(|err| DictionaryError::Serialize(err.to_string()))
+    }
+
+    /// Add a word to the deck. The review state of a word which is already in the deck is kept.
+    /// Returns false when the word was already in the deck
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - &str
+    /// * `word` - &Word
+    /// * `now` - u64
+    pub fn add_word(&mut self, key: &str, word: &Word, now: u64) -> bool {
+        if self.cards.contains_key(key) {
+            return false;
+        }
+
+        self.cards
+            .insert(key.to_string(), Card::new(key, word, now));
+
+        true
+    }
+
+    /// Add the words of a parse result to the deck. Returns the number of cards which have been created
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - &WordParserResult
+    /// * `now` - u64
+    pub fn add_words(&mut self, words: &WordParserResult, now: u64) -> usize {
+        words
+            .iter()
+            .filter(|(key, word)| self.add_word(key, word, now))
+            .count()
+    }
+
+    /// Get a card of the deck
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - &str
+    pub fn card(&self, key: &str) -> Option<&Card> {
+        self.cards.get(key)
+    }
+
+    /// Get the cards which need to be reviewed. The cards which are the most overdue come first
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - u64
+    pub fn due_cards(&self, now: u64) -> Vec<&Card> {
+        let mut cards = self
+            .cards
+            .values()
+            .filter(|card| card.is_due(now))
+            .collect::<Vec<_>>();
+
+        cards.sort_by(|a, b| a.due.cmp(&b.due).then_with(|| a.key.cmp(&b.key)));
+
+        cards
+    }
+
+    /// Record the review of a word. None is returned when the word isn't in the deck
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - &str
+    /// * `grade` - Grade
+    /// * `now` - u64
+    pub fn record_review(&mut self, word: &str, grade: Grade, now: u64) -> Option<&Card> {
+        let card = self.cards.get_mut(word)?;
+        card.review(grade, now);
+
+        Some(card)
+    }
+
+    /// Get the number of cards of the deck
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Whether the deck has no card
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+}
+
+/// Get the current unix timestamp in seconds. Every function of the module takes the time as an argument
+/// so that the scheduling is deterministic; this helper can be used to get the current time
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn deck() -> Deck {
+        let words = WordParserResult::from([
+            ("你好".to_string(), Word::default()),
+            ("喜歡".to_string(), Word::default()),
+        ]);
+
+        let mut deck = Deck::new();
+        assert_eq!(deck.add_words(&words, NOW), 2);
+        assert_eq!(deck.add_words(&words, NOW), 0);
+
+        deck
+    }
+
+    #[test]
+    fn expect_to_schedule_with_sm2() {
+        let mut deck = deck();
+
+        let card = deck.record_review("你好", Grade::Good, NOW).unwrap();
+        assert_eq!((card.repetitions, card.interval), (1, 1));
+        assert_eq!(card.due, NOW + DAY);
+        assert_eq!(card.ease, 2.5);
+
+        let card = deck
+            .record_review("你好", Grade::Perfect, NOW + DAY)
+            .unwrap();
+        assert_eq!(card.interval, 6);
+        assert!((card.ease - 2.6).abs() < 1e-9);
+
+        let card = deck
+            .record_review("你好", Grade::Good, NOW + 7 * DAY)
+            .unwrap();
+        assert_eq!(card.interval, 16);
+
+        let card = deck
+            .record_review("你好", Grade::Blackout, NOW + 23 * DAY)
+            .unwrap();
+        assert_eq!((card.repetitions, card.interval, card.lapses), (0, 1, 1));
+        assert!((card.ease - 1.8).abs() < 1e-9);
+
+        assert!(deck.record_review("再見", Grade::Good, NOW).is_none());
+    }
+
+    #[test]
+    fn expect_to_get_due_cards() {
+        let mut deck = deck();
+        assert_eq!(deck.due_cards(NOW).len(), 2);
+
+        deck.record_review("喜歡", Grade::Good, NOW);
+        let due = deck.due_cards(NOW + 1);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].key, "你好");
+        assert_eq!(deck.due_cards(NOW + DAY).len(), 2);
+    }
+
+    #[test]
+    fn expect_to_save_and_load_json() {
+        let mut deck = deck();
+        deck.record_review("你好", Grade::Familiar, NOW);
+
+        let json = deck.to_json().unwrap();
+        assert_eq!(Deck::from_json(&json).unwrap(), deck);
+        assert_eq!(Grade::from_quality(4), Some(Grade::Good));
+        assert!(Grade::from_quality(6).is_none());
+    }
+}
@@ -1,3 +1,8 @@
+#[cfg(feature = "chinese")]
+use crate::error::DictionaryError;
+#[cfg(feature = "chinese")]
+use std::{fs::File, io::BufReader, path::PathBuf};
+
 /// clean_sentence from a sentence to avoid being count
 ///
 /// # Arguments
@@ -12,3 +17,19 @@ pub fn clean_sentence<S: AsRef<str>>(sentence: S, punctuations: &[String]) -> St
 
     filtered_sentence
 }
+
+/// Open a data file with a buffered reader
+///
+/// # Arguments
+///
+/// * `path` - PathBuf
+/// * `error` - fn(String) -> DictionaryError
+#[cfg(feature = "chinese")]
+pub(crate) fn open_reader(
+    path: PathBuf,
+    error: fn(String) -> DictionaryError,
+) -> Result<BufReader<File>, DictionaryError> {
+    let file = File::open(path).map_err(|err| error(err.to_string()))?;
+
+    Ok(BufReader::new(file))
+}
@@ -0,0 +1,239 @@
+use super::{Word, WordParserResult};
+use crate::error::DictionaryError;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Share of the words a reader should know to understand a text. It's used to estimate the level of a text
+const COVERAGE: f64 = 0.9;
+
+/// Vocabulary list used to grade the words
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub enum LevelSystem {
+    /// HSK 2.0 from 1 to 6
+    Hsk2,
+    /// HSK 3.0 from 1 to 9
+    Hsk3,
+    /// TOCFL
+    Tocfl,
+}
+
+impl LevelSystem {
+    /// Get the short name of the system e.g: hsk2
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LevelSystem::Hsk2 => "hsk2",
+            LevelSystem::Hsk3 => "hsk3",
+            LevelSystem::Tocfl => "tocfl",
+        }
+    }
+}
+
+/// Level of a word in a vocabulary list e.g: HSK 2.0 level 3
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct Level {
+    pub system: LevelSystem,
+    pub level: u8,
+}
+
+impl Level {
+    /// Parse the level of a vocabulary list. The first number is used which allow to parse labels
+    /// such as "HSK3" or a band such as "7-9"
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - LevelSystem
+    /// * `value` - &str
+    pub fn parse(system: LevelSystem, value: &str) -> Option<Self> {
+        let start = value.find(|c: char| c.is_ascii_digit())?;
+        let digits = value[start..]
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect::<String>();
+
+        Some(Level {
+            system,
+            level: digits.parse().ok()?,
+        })
+    }
+}
+
+impl FromStr for Level {
+    type Err = DictionaryError;
+
+    /// Parse a level formatted with the name of its system e.g: hsk2-3
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (system, level) = value
+            .trim()
+            .rsplit_once('-')
+            .ok_or_else(|| DictionaryError::Serialize(format!("invalid level {value}")))?;
+
+        let system = match system {
+            "hsk2" => LevelSystem::Hsk2,
+            "hsk3" => LevelSystem::Hsk3,
+            "tocfl" => LevelSystem::Tocfl,
+            _ => return Err(DictionaryError::Serialize(format!("invalid level {value}"))),
+        };
+
+        Level::parse(system, level)
+            .ok_or_else(|| DictionaryError::Serialize(format!("invalid level {value}")))
+    }
+}
+
+impl fmt::Display for Level {
+    /// Format the level with the name of its system e.g: hsk2-3
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.system.as_str(), self.level)
+    }
+}
+
+/// Difficulty of a text based on the levels of its words. Every value is computed with the number
+/// of occurrences of the words
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LevelReport {
+    pub system: LevelSystem,
+    /// Number of words for each level
+    pub distribution: BTreeMap<u8, i128>,
+    /// Number of words which don't have a level in the system
+    pub unknown: i128,
+    pub total: i128,
+    pub target: u8,
+    /// Percent of the words which are above the target level. Words without level are counted as beyond
+    pub beyond_target: f64,
+    /// Lowest level which allows to know 90% of the words. None when the words of the highest level
+    /// aren't enough
+    pub estimated_level: Option<u8>,
+}
+
+pub trait LevelReporter {
+    /// Compute the difficulty of the words for a vocabulary list
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - LevelSystem
+    /// * `target` - u8
+    fn level_report(&self, system: LevelSystem, target: u8) -> LevelReport;
+}
+
+impl LevelReporter for WordParserResult {
+    fn level_report(&self, system: LevelSystem, target: u8) -> LevelReport {
+        let mut distribution = BTreeMap::new();
+        let mut unknown = 0;
+
+        for word in self.values() {
+            match word.level(system) {
+                Some(level) => *distribution.entry(level).or_default() += word.count,
+                None => unknown += word.count,
+            }
+        }
+
+        let total = distribution.values().sum::<i128>() + unknown;
+        let beyond = distribution
+            .range(target.saturating_add(1)..)
+            .map(|(_, count)| count)
+            .sum::<i128>()
+            + unknown;
+
+        let mut known = 0;
+        let estimated_level = distribution
+            .iter()
+            .find(|(_, count)| {
+                known += **count;
+                known as f64 >= total as f64 * COVERAGE
+            })
+            .map(|(level, _)| *level);
+
+        LevelReport {
+            system,
+            distribution,
+            unknown,
+            total,
+            target,
+            beyond_target: percent(beyond, total),
+            estimated_level,
+        }
+    }
+}
+
+impl Word {
+    /// Get the level of the word in a vocabulary list
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - LevelSystem
+    pub fn level(&self, system: LevelSystem) -> Option<u8> {
+        self.levels
+            .iter()
+            .find(|level| level.system == system)
+            .map(|level| level.level)
+    }
+}
+
+/// Compute a percentage. An empty text has a percentage of 0
+///
+/// # Arguments
+///
+/// * `value` - i128
+/// * `total` - i128
+fn percent(value: i128, total: i128) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+
+    value as f64 * 100.0 / total as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(count: i128, level: Option<u8>) -> Word {
+        Word {
+            count,
+            levels: level
+                .map(|level| Level {
+                    system: LevelSystem::Hsk2,
+                    level,
+                })
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn expect_to_parse_level() {
+        assert_eq!(Level::parse(LevelSystem::Hsk3, "7-9").unwrap().level, 7);
+        assert_eq!(Level::parse(LevelSystem::Hsk2, "HSK3").unwrap().level, 3);
+        assert!(Level::parse(LevelSystem::Tocfl, "").is_none());
+    }
+
+    #[test]
+    fn expect_to_compute_level_report() {
+        let words = WordParserResult::from([
+            ("我".to_string(), word(10, Some(1))),
+            ("喜歡".to_string(), word(6, Some(1))),
+            ("咖啡".to_string(), word(2, Some(2))),
+            ("濃縮".to_string(), word(1, Some(5))),
+            ("馬克".to_string(), word(1, None)),
+        ]);
+
+        let report = words.level_report(LevelSystem::Hsk2, 2);
+        assert_eq!(report.total, 20);
+        assert_eq!(
+            report.distribution,
+            BTreeMap::from([(1, 16), (2, 2), (5, 1)])
+        );
+        assert_eq!(report.unknown, 1);
+        assert_eq!(report.beyond_target, 10.0);
+        assert_eq!(report.estimated_level, Some(2));
+
+        assert!(
+            words
+                .level_report(LevelSystem::Tocfl, 2)
+                .estimated_level
+                .is_none()
+        );
+    }
+}
@@ -1,11 +1,28 @@
 use crate::error::DictionaryError;
-use crate::export::{self, Export};
+use crate::export::{self, Export, ExportOptions, Format};
 use serde::Serialize;
 use std::collections::BTreeMap;
+use std::io::Write;
+
+pub mod level;
+pub mod part_of_speech;
+pub mod segment;
+
+pub use level::{Level, LevelReporter, LevelSystem};
+pub use part_of_speech::{PartOfSpeech, PartOfSpeechFilter};
+pub use segment::{Reading, Romanizer, SentenceSegmenter, TextSegment};
 
 /// Type alias to BTreeMap<String, Word>
 pub type WordParserResult = BTreeMap<String, Word>;
 
+/// A meaning of a word. It keeps the pronunciation and the parts of speech which belong to the translation
+#[derive(Default, Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Sense {
+    pub pronunciation: String,
+    pub translation: String,
+    pub parts_of_speech: Vec<PartOfSpeech>,
+}
+
 #[derive(Default, Clone, Debug, Serialize)]
 pub struct Word {
     #[serde(serialize_with = "export::serialize_vec_to_string")]
@@ -17,6 +34,17 @@ pub struct Word {
     #[serde(serialize_with = "export::serialize_vec_to_string")]
     pub translations: Vec<String>,
     pub count: i128,
+
+    #[serde(serialize_with = "export::serialize_vec_to_string")]
+    pub parts_of_speech: Vec<PartOfSpeech>,
+
+    /// Senses of the word. These are not exported to the CSV as the translations & pronunciations already contains them
+    #[serde(skip)]
+    pub senses: Vec<Sense>,
+
+    /// Levels of the word in the vocabulary lists which have been loaded (e.g: HSK, TOCFL)
+    #[serde(skip)]
+    pub levels: Vec<Level>,
 }
 
 pub trait WordParser {
@@ -48,9 +76,17 @@ pub trait WordParser {
 }
 
 impl Export for WordParserResult {
-    fn to_csv(&self) -> Result<String, DictionaryError> {
-        let items = self.values().collect::<Vec<_>>();
-
-        export::export_to_csv(items)
+    fn write_to<W: Write>(
+        &self,
+        format: Format,
+        options: &ExportOptions,
+        writer: &mut W,
+    ) -> Result<(), DictionaryError> {
+        export::write_words(
+            self.iter().map(|(key, word)| (key.as_str(), word)),
+            format,
+            options,
+            writer,
+        )
     }
 }
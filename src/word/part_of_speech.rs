@@ -0,0 +1,166 @@
+use super::{Word, WordParserResult};
+use serde::Serialize;
+
+/// Part of speech of a word. Abbreviations used by the dictionaries are mapped to the variants
+/// and the unknown ones are kept in Other
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum PartOfSpeech {
+    Noun,
+    Verb,
+    Adjective,
+    Adverb,
+    Preposition,
+    Pronoun,
+    Conjunction,
+    Idiom,
+    Interjection,
+    Numeral,
+    Prefix,
+    Suffix,
+    Other(String),
+}
+
+impl PartOfSpeech {
+    /// Parse a list of parts of speech separated by a comma e.g: "n,adj"
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - &str
+    pub fn parse_list(value: &str) -> Vec<PartOfSpeech> {
+        value
+            .split(',')
+            .map(PartOfSpeech::from)
+            .filter(|pos| !matches!(pos, PartOfSpeech::Other(o) if o.is_empty()))
+            .fold(Vec::new(), |mut acc, pos| {
+                if !acc.contains(&pos) {
+                    acc.push(pos);
+                }
+                acc
+            })
+    }
+
+    /// Get the abbreviation of the part of speech
+    pub fn as_str(&self) -> &str {
+        match self {
+            PartOfSpeech::Noun => "n",
+            PartOfSpeech::Verb => "v",
+            PartOfSpeech::Adjective => "adj",
+            PartOfSpeech::Adverb => "adv",
+            PartOfSpeech::Preposition => "prep",
+            PartOfSpeech::Pronoun => "pron",
+            PartOfSpeech::Conjunction => "conj",
+            PartOfSpeech::Idiom => "idiom",
+            PartOfSpeech::Interjection => "interj",
+            PartOfSpeech::Numeral => "num",
+            PartOfSpeech::Prefix => "prefix",
+            PartOfSpeech::Suffix => "suffix",
+            PartOfSpeech::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for PartOfSpeech {
+    fn from(value: &str) -> Self {
+        let value = value
+            .trim()
+            .trim_end_matches(['.', ':'])
+            .to_lowercase()
+            .replace('.', "");
+
+        match value.as_str() {
+            "n" => PartOfSpeech::Noun,
+            "v" | "vi" | "vt" => PartOfSpeech::Verb,
+            "adj" | "adjl" => PartOfSpeech::Adjective,
+            "adv" => PartOfSpeech::Adverb,
+            "prep" | "pre" => PartOfSpeech::Preposition,
+            "pron" | "pro" => PartOfSpeech::Pronoun,
+            "conj" | "con" => PartOfSpeech::Conjunction,
+            "idiom" | "idom" | "proverb" => PartOfSpeech::Idiom,
+            "interj" | "interjj" | "inter" | "int" => PartOfSpeech::Interjection,
+            "num" | "numeral" => PartOfSpeech::Numeral,
+            "prefix" => PartOfSpeech::Prefix,
+            "suffix" => PartOfSpeech::Suffix,
+            _ => PartOfSpeech::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for PartOfSpeech {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+pub trait PartOfSpeechFilter {
+    /// Keep only the words which have at least one of the given parts of speech
+    ///
+    /// # Arguments
+    ///
+    /// * `parts_of_speech` - &[PartOfSpeech]
+    fn filter_by_part_of_speech(self, parts_of_speech: &[PartOfSpeech]) -> Self;
+}
+
+impl PartOfSpeechFilter for WordParserResult {
+    fn filter_by_part_of_speech(self, parts_of_speech: &[PartOfSpeech]) -> Self {
+        self.into_iter()
+            .filter(|(_, word)| word.has_part_of_speech(parts_of_speech))
+            .collect()
+    }
+}
+
+impl Word {
+    /// Check whether the word has at least one of the given parts of speech
+    ///
+    /// # Arguments
+    ///
+    /// * `parts_of_speech` - &[PartOfSpeech]
+    pub fn has_part_of_speech(&self, parts_of_speech: &[PartOfSpeech]) -> bool {
+        self.parts_of_speech
+            .iter()
+            .any(|pos| parts_of_speech.contains(pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_parse_parts_of_speech() {
+        assert_eq!(PartOfSpeech::from("Adj"), PartOfSpeech::Adjective);
+        assert_eq!(PartOfSpeech::from("v.t"), PartOfSpeech::Verb);
+        assert_eq!(PartOfSpeech::from("prefix:"), PartOfSpeech::Prefix);
+        assert_eq!(
+            PartOfSpeech::from("anat"),
+            PartOfSpeech::Other("anat".to_string())
+        );
+        assert_eq!(
+            PartOfSpeech::parse_list("n, adv,n"),
+            vec![PartOfSpeech::Noun, PartOfSpeech::Adverb]
+        );
+        assert!(PartOfSpeech::parse_list("").is_empty());
+    }
+
+    #[test]
+    fn expect_to_filter_words_by_part_of_speech() {
+        let words = WordParserResult::from([
+            (
+                "ກິນ".to_string(),
+                Word {
+                    parts_of_speech: vec![PartOfSpeech::Verb],
+                    ..Default::default()
+                },
+            ),
+            (
+                "ເຂົ້າ".to_string(),
+                Word {
+                    parts_of_speech: vec![PartOfSpeech::Noun],
+                    ..Default::default()
+                },
+            ),
+        ]);
+
+        let verbs = words.filter_by_part_of_speech(&[PartOfSpeech::Verb]);
+        assert_eq!(verbs.keys().collect::<Vec<_>>(), vec!["ກິນ"]);
+    }
+}
@@ -0,0 +1,124 @@
+use super::Word;
+
+/// A part of a sentence. Unlike the words of a parse result, the segments are kept in the order of the
+/// sentence and the punctuations are kept so that the sentence can be rendered again
+#[derive(Debug, Clone, Default)]
+pub struct TextSegment {
+    /// Text of the segment as written in the sentence
+    pub text: String,
+    /// Definition of the segment. None for a punctuation or a word which is not present in the dictionary
+    pub word: Option<Word>,
+    pub punctuation: bool,
+}
+
+impl TextSegment {
+    /// Whether the segment is a word which has not been found in the dictionary. Spaces, digits and
+    /// punctuations are not considered as unknown words
+    pub fn is_unknown(&self) -> bool {
+        !self.punctuation && self.word.is_none() && self.text.chars().any(char::is_alphabetic)
+    }
+}
+
+/// Reading of a syllable of a segment e.g: the pinyin of a chinese character
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reading {
+    pub text: String,
+    /// Name of the tone of the syllable e.g: tone3, tone-mid
+    pub tone: Option<String>,
+}
+
+/// Romanize the segments of a sentence. It's used by the renderers which write the reading of a word
+/// e.g: the ruby annotations or the interlinear glosses
+pub trait Romanizer {
+    /// Get the readings of the syllables of a segment
+    ///
+    /// # Arguments
+    ///
+    /// * `segment` - &TextSegment
+    fn readings(&self, segment: &TextSegment) -> Vec<Reading>;
+}
+
+pub trait SentenceSegmenter {
+    /// Split a sentence into segments in the order of the sentence. Every character of the sentence
+    /// is kept in a segment
+    ///
+    /// # Arguments
+    ///
+    /// * `sentence` - S
+    fn segment_sentence<S: AsRef<str>>(&self, sentence: S) -> Vec<TextSegment>;
+}
+
+/// Split a sentence into runs of text and runs of punctuations. The boolean is true for the punctuations
+///
+/// # Arguments
+///
+/// * `sentence` - &str
+/// * `punctuations` - &[String]
+#[cfg(any(feature = "chinese", feature = "laotian"))]
+pub(crate) fn split_punctuation<'a>(
+    sentence: &'a str,
+    punctuations: &[String],
+) -> Vec<(&'a str, bool)> {
+    let mut runs: Vec<(&str, bool)> = Vec::new();
+    let (mut start, mut idx) = (0, 0);
+    let mut in_punctuation = false;
+
+    while idx < sentence.len() {
+        let matched = punctuations
+            .iter()
+            .filter(|p| !p.is_empty() && sentence[idx..].starts_with(p.as_str()))
+            .map(String::len)
+            .max();
+
+        let is_punctuation = matched.is_some();
+        if is_punctuation != in_punctuation && idx > start {
+            runs.push((&sentence[start..idx], in_punctuation));
+            start = idx;
+        }
+        in_punctuation = is_punctuation;
+
+        idx += matched.unwrap_or_else(|| sentence[idx..].chars().next().map_or(1, char::len_utf8));
+    }
+
+    if start < sentence.len() {
+        runs.push((&sentence[start..], in_punctuation));
+    }
+
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_mark_unknown_segments() {
+        let segment = |text: &str, punctuation: bool| TextSegment {
+            text: text.to_string(),
+            punctuation,
+            ..Default::default()
+        };
+
+        assert!(segment("ຟຼິບ", false).is_unknown());
+        assert!(!segment("。", true).is_unknown());
+        assert!(!segment("2024 ", false).is_unknown());
+    }
+
+    #[cfg(any(feature = "chinese", feature = "laotian"))]
+    #[test]
+    fn expect_to_split_punctuation() {
+        let punctuations = vec!["。".to_string(), " ".to_string(), ",".to_string()];
+
+        assert_eq!(
+            split_punctuation("你好。 我, 是", &punctuations),
+            vec![
+                ("你好", false),
+                ("。 ", true),
+                ("我", false),
+                (", ", true),
+                ("是", false)
+            ]
+        );
+        assert!(split_punctuation("", &punctuations).is_empty());
+    }
+}